@@ -0,0 +1,45 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Embed the original sources of the stock exercises in the binary, so that
+// `rustlings reset` can restore them. They are read from the committed
+// `pristine/` tree, never from `exercises/`, which the learner edits.
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-changed=pristine");
+    let mut files = Vec::new();
+    collect(Path::new("pristine/exercises"), &mut files)?;
+    files.sort();
+
+    let mut source = String::from("static EMBEDDED: &[(&str, &[u8])] = &[\n");
+    for file in files {
+        let name = file
+            .strip_prefix("pristine")
+            .unwrap()
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let absolute = fs::canonicalize(&file)?;
+        source.push_str(&format!(
+            "    ({:?}, include_bytes!({:?})),\n",
+            name, absolute
+        ));
+    }
+    source.push_str("];\n");
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("pristine.rs");
+    fs::write(out, source)
+}
+
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -11,17 +11,17 @@
 // Execute the command `rustlings hint hashmap1` if you need
 // hints.
 
+// I AM NOT DONE
+
 use std::collections::HashMap;
 
 fn fruit_basket() -> HashMap<String, u32> {
-    let mut basket = HashMap::new(); // TODO: declare your hash map here.
+    let mut basket = // TODO: declare your hash map here.
 
     // Two bananas are already given for you :)
     basket.insert(String::from("banana"), 2);
 
     // TODO: Put more fruits in your basket here.
-    basket.insert(String::from("apple"), 1);
-    basket.insert(String::from("mango"), 2);
 
     basket
 }
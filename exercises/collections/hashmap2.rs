@@ -12,6 +12,8 @@
 // Execute the command `rustlings hint hashmap2` if you need
 // hints.
 
+// I AM NOT DONE
+
 use std::collections::HashMap;
 
 #[derive(Hash, PartialEq, Eq)]
@@ -36,7 +38,6 @@ fn fruit_basket(basket: &mut HashMap<Fruit, u32>) {
         // TODO: Put new fruits if not already present. Note that you
         // are not allowed to put any type of fruit that's already
         // present!
-        basket.entry(fruit).or_insert(2);
     }
 }
 
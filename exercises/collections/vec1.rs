@@ -4,10 +4,11 @@
 // Make me compile and pass the test!
 // Execute the command `rustlings hint vec1` if you need hints.
 
+// I AM NOT DONE
+
 fn array_and_vec() -> ([i32; 4], Vec<i32>) {
     let a = [10, 20, 30, 40]; // a plain array
     let v = // TODO: declare your vector here with the macro for vectors
-    Vec::from(a);
 
     (a, v)
 }
@@ -7,11 +7,12 @@
 // Execute the command `rustlings hint vec2` if you need
 // hints.
 
+// I AM NOT DONE
+
 fn vec_loop(mut v: Vec<i32>) -> Vec<i32> {
     for i in v.iter_mut() {
         // TODO: Fill this up so that each element in the Vec `v` is
         // multiplied by 2.
-        *i *= 2;
     }
 
     // At this point, `v` should be equal to [4, 8, 12, 16, 20].
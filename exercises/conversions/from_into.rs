@@ -33,30 +33,10 @@ impl Default for Person {
 // If while parsing the age, something goes wrong, then return the default of Person
 // Otherwise, then return an instantiated Person object with the results
 
+// I AM NOT DONE
+
 impl From<&str> for Person {
-    fn from(s: &str) -> Person {
-        if s.len() == 0 {
-            Person::default()
-        } else {
-            let params: Vec<&str> = s.split(",").collect();
-            if params.len() != 2 {
-                Person::default()
-            } else {
-                if params[0] == "" {
-                    Person::default()
-                } else {
-                    let age_result = params[1].parse();
-                    match age_result {
-                        Ok(age) => Person {
-                            name: params[0].to_string(),
-                            age: age,
-                        },
-                        Err(_) => Person::default(),
-                    }
-                }
-            }
-        }
-    }
+    fn from(s: &str) -> Person {}
 }
 
 fn main() {
@@ -4,7 +4,6 @@
 // You can read more about it at https://doc.rust-lang.org/std/convert/trait.TryFrom.html
 use std::convert::{TryFrom, TryInto};
 use std::error;
-use std::fmt;
 
 #[derive(Debug, PartialEq)]
 struct Color {
@@ -22,84 +21,24 @@ struct Color {
 // but the slice implementation needs to check the slice length!
 // Also note that correct RGB color values must be integers in the 0..=255 range.
 
-fn valid_range(val: &i16) -> bool {
-    *val >= 0_i16 && *val <= 255_i16
-}
-
-#[derive(Debug)]
-enum ColorConvertError {
-    OutofRangeError,
-    InsufficientParamsError,
-}
-
-impl fmt::Display for ColorConvertError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match *self {
-            ColorConvertError::OutofRangeError => write!(f, "Out of range (0, 255)."),
-            ColorConvertError::InsufficientParamsError => write!(f, "Should exist 3 params."),
-        }
-    }
-}
-
-impl error::Error for ColorConvertError {
-    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
-        Some(self)
-    }
-}
+// I AM NOT DONE
 
 // Tuple implementation
 impl TryFrom<(i16, i16, i16)> for Color {
     type Error = Box<dyn error::Error>;
-    fn try_from(tuple: (i16, i16, i16)) -> Result<Self, Self::Error> {
-        let (r, g, b) = tuple;
-        if valid_range(&r) && valid_range(&g) && valid_range(&b) {
-            Ok(Color {
-                red: r as u8,
-                green: g as u8,
-                blue: b as u8,
-            })
-        } else {
-            Err(Box::new(ColorConvertError::OutofRangeError))
-        }
-    }
+    fn try_from(tuple: (i16, i16, i16)) -> Result<Self, Self::Error> {}
 }
 
 // Array implementation
 impl TryFrom<[i16; 3]> for Color {
     type Error = Box<dyn error::Error>;
-    fn try_from(arr: [i16; 3]) -> Result<Self, Self::Error> {
-        for c in arr {
-            if !valid_range(&c) {
-                return Err(Box::new(ColorConvertError::OutofRangeError));
-            }
-        }
-        Ok(Color {
-            red: arr[0] as u8,
-            green: arr[1] as u8,
-            blue: arr[2] as u8,
-        })
-    }
+    fn try_from(arr: [i16; 3]) -> Result<Self, Self::Error> {}
 }
 
 // Slice implementation
 impl TryFrom<&[i16]> for Color {
     type Error = Box<dyn error::Error>;
-    fn try_from(slice: &[i16]) -> Result<Self, Self::Error> {
-        if slice.len() != 3 {
-            return Err(Box::new(ColorConvertError::InsufficientParamsError));
-        } else {
-            for c in slice {
-                if !valid_range(&c) {
-                    return Err(Box::new(ColorConvertError::OutofRangeError));
-                }
-            }
-            Ok(Color {
-                red: slice[0] as u8,
-                green: slice[1] as u8,
-                blue: slice[2] as u8,
-            })
-        }
-    }
+    fn try_from(slice: &[i16]) -> Result<Self, Self::Error> {}
 }
 
 fn main() {
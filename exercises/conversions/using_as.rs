@@ -5,9 +5,11 @@
 // The goal is to make sure that the division does not fail to compile
 // and returns the proper type.
 
+// I AM NOT DONE
+
 fn average(values: &[f64]) -> f64 {
     let total = values.iter().fold(0.0, |a, b| a + b);
-    total / (values.len() as f64)
+    total / values.len()
 }
 
 fn main() {
@@ -1,13 +1,11 @@
 // enums2.rs
 // Make me compile! Execute `rustlings hint enums2` for hints!
 
+// I AM NOT DONE
+
 #[derive(Debug)]
 enum Message {
     // TODO: define the different variants used below
-    Move { x: i32, y: i32 },
-    Echo(String),
-    ChangeColor(u32, u32, u32),
-    Quit,
 }
 
 impl Message {
@@ -1,12 +1,10 @@
 // enums3.rs
 // Address all the TODOs to make the tests pass!
 
+// I AM NOT DONE
+
 enum Message {
     // TODO: implement the message variant types based on their usage below
-    Move(Point),
-    Quit,
-    Echo(String),
-    ChangeColor((u8, u8, u8)),
 }
 
 struct Point {
@@ -39,12 +37,6 @@ impl State {
 
     fn process(&mut self, message: Message) {
         // TODO: create a match expression to process the different message variants
-        match message {
-            Message::ChangeColor(color) => State::change_color(self, color),
-            Message::Echo(msg) => State::echo(self, msg),
-            Message::Move(point) => State::move_position(self, point),
-            Message::Quit => State::quit(self),
-        }
     }
 }
 
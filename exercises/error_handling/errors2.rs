@@ -16,20 +16,16 @@
 // There are at least two ways to implement this that are both correct-- but
 // one is a lot shorter! Execute `rustlings hint errors2` for hints to both ways.
 
+// I AM NOT DONE
+
 use std::num::ParseIntError;
 
 pub fn total_cost(item_quantity: &str) -> Result<i32, ParseIntError> {
     let processing_fee = 1;
     let cost_per_item = 5;
-    // ---  #1 method  ---
-    // let qty = item_quantity.parse::<i32>();
-    // match qty {
-    //     Ok(qty) => Ok(qty * cost_per_item + processing_fee),
-    //     Err(e) => Err(e),
-    // }
-
-    // --- #2 shortcut method ---
-    Ok(item_quantity.parse::<i32>()? * cost_per_item + processing_fee)
+    let qty = item_quantity.parse::<i32>();
+
+    Ok(qty * cost_per_item + processing_fee)
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@
 // Why not? What should we do to fix it?
 // Execute `rustlings hint errors3` for hints!
 
-// "?" is only used inside the function returning "Result" Enum.
+// I AM NOT DONE
 
 use std::num::ParseIntError;
 
@@ -12,7 +12,7 @@ fn main() {
     let mut tokens = 100;
     let pretend_user_input = "8";
 
-    let cost = total_cost(pretend_user_input).unwrap();
+    let cost = total_cost(pretend_user_input)?;
 
     if cost > tokens {
         println!("You can't afford that many!");
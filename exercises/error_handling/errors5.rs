@@ -4,11 +4,14 @@
 // It won't compile right now! Why?
 // Execute `rustlings hint errors5` for hints!
 
+// I AM NOT DONE
+
 use std::error;
 use std::fmt;
+use std::num::ParseIntError;
 
 // TODO: update the return type of `main()` to make this compile.
-fn main() -> Result<(), Box<dyn error::Error>> {
+fn main() -> Result<(), ParseIntError> {
     let pretend_user_input = "42";
     let x: i64 = pretend_user_input.parse()?;
     println!("output={:?}", PositiveNonzeroInteger::new(x)?);
@@ -8,6 +8,8 @@
 
 // Make these tests pass! Execute `rustlings hint errors6` for hints :)
 
+// I AM NOT DONE
+
 use std::num::ParseIntError;
 
 // This is a custom error type that we will be using in `parse_pos_nonzero()`.
@@ -22,17 +24,12 @@ impl ParsePosNonzeroError {
         ParsePosNonzeroError::Creation(err)
     }
     // TODO: add another error conversion function here.
-    fn from_parse_int(err: ParseIntError) -> ParsePosNonzeroError {
-        ParsePosNonzeroError::ParseInt(err)
-    }
 }
 
 fn parse_pos_nonzero(s: &str) -> Result<PositiveNonzeroInteger, ParsePosNonzeroError> {
     // TODO: change this to return an appropriate error instead of panicking
     // when `parse()` returns an error.
-    let x: i64 = s
-        .parse::<i64>()
-        .map_err(ParsePosNonzeroError::from_parse_int)?;
+    let x: i64 = s.parse().unwrap();
     PositiveNonzeroInteger::new(x).map_err(ParsePosNonzeroError::from_creation)
 }
 
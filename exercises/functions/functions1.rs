@@ -1,8 +1,8 @@
 // functions1.rs
 // Make me compile! Execute `rustlings hint functions1` for hints :)
 
+// I AM NOT DONE
+
 fn main() {
     call_me();
 }
-
-fn call_me() {}
@@ -1,11 +1,13 @@
 // functions5.rs
 // Make me compile! Execute `rustlings hint functions5` for hints :)
 
+// I AM NOT DONE
+
 fn main() {
     let answer = square(3);
     println!("The answer is {}", answer);
 }
 
 fn square(num: i32) -> i32 {
-    num * num
+    num * num;
 }
@@ -3,7 +3,9 @@
 
 // Execute `rustlings hint generics1` for hints!
 
+// I AM NOT DONE
+
 fn main() {
-    let mut shopping_list: Vec<&str> = Vec::new();
+    let mut shopping_list: Vec<?> = Vec::new();
     shopping_list.push("milk");
 }
@@ -3,12 +3,14 @@
 
 // Execute `rustlings hint generics2` for hints!
 
-struct Wrapper<T> {
-    value: T,
+// I AM NOT DONE
+
+struct Wrapper {
+    value: u32,
 }
 
-impl<T> Wrapper<T> {
-    pub fn new(value: T) -> Self {
+impl Wrapper {
+    pub fn new(value: u32) -> Self {
         Wrapper { value }
     }
 }
@@ -1,16 +1,13 @@
 // if1.rs
 
+// I AM NOT DONE
+
 pub fn bigger(a: i32, b: i32) -> i32 {
     // Complete this function to return the bigger number!
     // Do not use:
     // - another function call
     // - additional variables
     // Execute `rustlings hint if1` for hints
-    if a < b {
-        b
-    } else {
-        a
-    }
 }
 
 // Don't mind this for now :)
@@ -4,13 +4,13 @@
 // Step 2: Get the bar_for_fuzz and default_to_baz tests passing!
 // Execute the command `rustlings hint if2` if you want a hint :)
 
+// I AM NOT DONE
+
 pub fn fizz_if_foo(fizzish: &str) -> &str {
     if fizzish == "fizz" {
         "foo"
-    } else if fizzish == "fuzz" {
-        "bar"
     } else {
-        "baz"
+        1
     }
 }
 
@@ -1,6 +1,8 @@
 // macros1.rs
 // Make me compile! Execute `rustlings hint macros1` for hints :)
 
+// I AM NOT DONE
+
 #[macro_export]
 macro_rules! my_macro {
     () => {
@@ -9,5 +11,5 @@ macro_rules! my_macro {
 }
 
 fn main() {
-    my_macro!();
+    my_macro();
 }
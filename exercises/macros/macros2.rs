@@ -1,11 +1,12 @@
 // macros2.rs
 // Make me compile! Execute `rustlings hint macros2` for hints :)
 
+// I AM NOT DONE
+
 fn main() {
     my_macro!();
 }
 
-#[macro_export]
 macro_rules! my_macro {
     () => {
         println!("Check out my macro!");
@@ -2,8 +2,9 @@
 // Make me compile, without taking the macro out of the module!
 // Execute `rustlings hint macros3` for hints :)
 
+// I AM NOT DONE
+
 mod macros {
-    #[macro_export]
     macro_rules! my_macro {
         () => {
             println!("Check out my macro!");
@@ -1,8 +1,10 @@
 // modules1.rs
 // Make me compile! Execute `rustlings hint modules1` for hints :)
 
+// I AM NOT DONE
+
 mod sausage_factory {
-    pub fn make_sausage() {
+    fn make_sausage() {
         println!("sausage!");
     }
 }
@@ -1,16 +1,18 @@
 // modules2.rs
 // Make me compile! Execute `rustlings hint modules2` for hints :)
 
+// I AM NOT DONE
+
 mod delicious_snacks {
     use self::fruits::PEAR as fruit;
     use self::veggies::CUCUMBER as veggie;
 
-    pub mod fruits {
+    mod fruits {
         pub const PEAR: &'static str = "Pear";
         pub const APPLE: &'static str = "Apple";
     }
 
-    pub mod veggies {
+    mod veggies {
         pub const CUCUMBER: &'static str = "Cucumber";
         pub const CARROT: &'static str = "Carrot";
     }
@@ -19,7 +21,7 @@ mod delicious_snacks {
 fn main() {
     println!(
         "favorite snacks: {} and {}",
-        delicious_snacks::fruits::PEAR,
-        delicious_snacks::veggies::CUCUMBER,
+        delicious_snacks::fruit,
+        delicious_snacks::veggie
     );
 }
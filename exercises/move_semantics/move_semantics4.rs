@@ -4,8 +4,12 @@
 // freshly created vector from fill_vec to its caller.
 // Execute `rustlings hint move_semantics4` for hints!
 
+// I AM NOT DONE
+
 fn main() {
-    let mut vec1 = fill_vec();
+    let vec0 = Vec::new();
+
+    let mut vec1 = fill_vec(vec0);
 
     println!("{} has length {} content `{:?}`", "vec1", vec1.len(), vec1);
 
@@ -16,7 +20,7 @@ fn main() {
 
 // `fill_vec()` no longer takes `vec: Vec<i32>` as argument
 fn fill_vec() -> Vec<i32> {
-    let mut vec = Vec::new();
+    let mut vec = vec;
 
     vec.push(22);
     vec.push(44);
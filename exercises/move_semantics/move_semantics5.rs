@@ -3,11 +3,13 @@
 // adding, changing or removing any of them.
 // Execute `rustlings hint move_semantics5` for hints :)
 
+// I AM NOT DONE
+
 fn main() {
     let mut x = 100;
     let y = &mut x;
     let z = &mut *y;
-    *z += 1000;
     *y += 100;
+    *z += 1000;
     assert_eq!(x, 1200);
 }
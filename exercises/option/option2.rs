@@ -1,10 +1,12 @@
 // option2.rs
 // Make me compile! Execute `rustlings hint option2` for hints
 
+// I AM NOT DONE
+
 fn main() {
     let optional_word = Some(String::from("rustlings"));
     // TODO: Make this an if let statement whose value is "Some" type
-    if let word = optional_word.unwrap() {
+    word = optional_word {
         println!("The word is: {}", word);
     } else {
         println!("The optional word doesn't contain anything");
@@ -17,13 +19,7 @@ fn main() {
 
     // TODO: make this a while let statement - remember that vector.pop also adds another layer of Option<T>
     // You can stack `Option<T>`'s into while let and if let
-    while let integer = optional_integers_vec.pop() {
-        match integer {
-            None => break,
-            Some(integer) => match integer {
-                Some(integer) => println!("current value: {}", integer),
-                None => break,
-            },
-        }
+    integer = optional_integers_vec.pop() {
+        println!("current value: {}", integer);
     }
 }
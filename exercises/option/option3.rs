@@ -1,6 +1,8 @@
 // option3.rs
 // Make me compile! Execute `rustlings hint option3` for hints
 
+// I AM NOT DONE
+
 struct Point {
     x: i32,
     y: i32,
@@ -9,7 +11,7 @@ struct Point {
 fn main() {
     let y: Option<Point> = Some(Point { x: 100, y: 200 });
 
-    match &y {
+    match y {
         Some(p) => println!("Co-ordinates are {},{} ", p.x, p.y),
         _ => println!("no match"),
     }
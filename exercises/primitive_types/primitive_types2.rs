@@ -1,6 +1,9 @@
 // primitive_types2.rs
 // Fill in the rest of the line that has code missing!
 // No hints, there's no tricks, just get used to typing these :)
+
+// I AM NOT DONE
+
 fn main() {
     // Characters (`char`)
 
@@ -13,9 +16,9 @@ fn main() {
         println!("Neither alphabetic nor numeric!");
     }
 
-    let your_character = '$'; // Finish this line like the example! What's your favorite character?
-                              // Try a letter, try a number, try a special character, try a character
-                              // from a different language than your own, try an emoji!
+    let // Finish this line like the example! What's your favorite character?
+    // Try a letter, try a number, try a special character, try a character
+    // from a different language than your own, try an emoji!
     if your_character.is_alphabetic() {
         println!("Alphabetical!");
     } else if your_character.is_numeric() {
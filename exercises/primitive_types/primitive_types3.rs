@@ -2,8 +2,10 @@
 // Create an array with at least 100 elements in it where the ??? is.
 // Execute `rustlings hint primitive_types3` for hints!
 
+// I AM NOT DONE
+
 fn main() {
-    let a = [0; 100];
+    let a = ???
 
     if a.len() >= 100 {
         println!("Wow, that's a big array!");
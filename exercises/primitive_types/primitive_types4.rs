@@ -2,11 +2,13 @@
 // Get a slice out of Array a where the ??? is so that the test passes.
 // Execute `rustlings hint primitive_types4` for hints!!
 
+// I AM NOT DONE
+
 #[test]
 fn slice_out_of_array() {
     let a = [1, 2, 3, 4, 5];
 
-    let nice_slice = &a[1..4];
+    let nice_slice = ???
 
     assert_eq!([2, 3, 4], nice_slice)
 }
@@ -7,15 +7,10 @@
 // more than 40 at once, each apple only costs 1! Write a function that calculates
 // the price of an order of apples given the quantity bought. No hints this time!
 
+// I AM NOT DONE
+
 // Put your function here!
 // fn ..... {
-fn calculate_apple_price(quantity: u32) -> u32 {
-    if (quantity <= 40) {
-        quantity * 2
-    } else {
-        quantity * 1
-    }
-}
 
 // Don't modify this function!
 #[test]
@@ -7,6 +7,8 @@
 // you think each value is. That is, add either `string_slice` or `string`
 // before the parentheses on each line. If you're right, it will compile!
 
+// I AM NOT DONE
+
 fn string_slice(arg: &str) {
     println!("{}", arg);
 }
@@ -15,14 +17,14 @@ fn string(arg: String) {
 }
 
 fn main() {
-    string_slice("blue");
-    string("red".to_string());
-    string(String::from("hi"));
-    string("rust is fun!".to_owned()); // I was wrong.
-    string_slice("nice weather".into());
-    string(format!("Interpolation {}", "Station"));
-    string_slice(&String::from("abc")[0..1]);
-    string_slice("  hello there ".trim());
-    string("Happy Monday!".to_string().replace("Mon", "Tues"));
-    string("mY sHiFt KeY iS sTiCkY".to_lowercase());
+    ???("blue");
+    ???("red".to_string());
+    ???(String::from("hi"));
+    ???("rust is fun!".to_owned());
+    ???("nice weather".into());
+    ???(format!("Interpolation {}", "Station"));
+    ???(&String::from("abc")[0..1]);
+    ???("  hello there ".trim());
+    ???("Happy Monday!".to_string().replace("Mon", "Tues"));
+    ???("mY sHiFt KeY iS sTiCkY".to_lowercase());
 }
@@ -7,6 +7,8 @@
 // we expect to get when we call `times_two` with a negative number.
 // No hints, you can do this :)
 
+// I AM NOT DONE
+
 pub fn times_two(num: i32) -> i32 {
     num * 2
 }
@@ -17,12 +19,12 @@ mod tests {
 
     #[test]
     fn returns_twice_of_positive_numbers() {
-        assert_eq!(times_two(4), 8);
+        assert_eq!(times_two(4), ???);
     }
 
     #[test]
     fn returns_twice_of_negative_numbers() {
         // TODO replace unimplemented!() with an assert for `times_two(-4)`
-        assert_eq!(times_two(-4), -8);
+        unimplemented!()
     }
 }
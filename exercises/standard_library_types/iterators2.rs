@@ -3,6 +3,8 @@
 // can offer. Follow the steps to complete the exercise.
 // As always, there are hints if you execute `rustlings hint iterators2`!
 
+// I AM NOT DONE
+
 // Step 1.
 // Complete the `capitalize_first` function.
 // "hello" -> "Hello"
@@ -10,7 +12,7 @@ pub fn capitalize_first(input: &str) -> String {
     let mut c = input.chars();
     match c.next() {
         None => String::new(),
-        Some(first) => [first.to_uppercase().to_string(), c.as_str().to_string()].join(""),
+        Some(first) => ???,
     }
 }
 
@@ -19,11 +21,7 @@ pub fn capitalize_first(input: &str) -> String {
 // Return a vector of strings.
 // ["hello", "world"] -> ["Hello", "World"]
 pub fn capitalize_words_vector(words: &[&str]) -> Vec<String> {
-    let mut capitalized_words = Vec::new();
-    for word in words.iter() {
-        capitalized_words.push(capitalize_first(word));
-    }
-    capitalized_words
+    vec![]
 }
 
 // Step 3.
@@ -31,7 +29,7 @@ pub fn capitalize_words_vector(words: &[&str]) -> Vec<String> {
 // Return a single string.
 // ["hello", " ", "world"] -> "Hello World"
 pub fn capitalize_words_string(words: &[&str]) -> String {
-    capitalize_words_vector(words).join("")
+    String::new()
 }
 
 #[cfg(test)]
@@ -6,6 +6,8 @@
 //    list_of_results functions.
 // Execute `rustlings hint iterators3` to get some hints!
 
+// I AM NOT DONE
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum DivisionError {
     NotDivisible(NotDivisibleError),
@@ -20,40 +22,20 @@ pub struct NotDivisibleError {
 
 // Calculate `a` divided by `b` if `a` is evenly divisible by `b`.
 // Otherwise, return a suitable error.
-pub fn divide(a: i32, b: i32) -> Result<i32, DivisionError> {
-    if b == 0 {
-        Err(DivisionError::DivideByZero)
-    } else if a % b != 0i32 {
-        Err(DivisionError::NotDivisible(NotDivisibleError {
-            dividend: a,
-            divisor: b,
-        }))
-    } else {
-        Ok(a / b)
-    }
-}
+pub fn divide(a: i32, b: i32) -> Result<i32, DivisionError> {}
 
 // Complete the function and return a value of the correct type so the test passes.
 // Desired output: Ok([1, 11, 1426, 3])
-fn result_with_list() -> Result<[i32; 4], DivisionError> {
+fn result_with_list() -> () {
     let numbers = vec![27, 297, 38502, 81];
     let division_results = numbers.into_iter().map(|n| divide(n, 27));
-    let mut results = [0; 4];
-    for (i, item) in division_results.enumerate() {
-        match item {
-            Ok(value) => results[i] = value,
-            Err(e) => return Err(e),
-        }
-    }
-    Ok(results)
 }
 
 // Complete the function and return a value of the correct type so the test passes.
 // Desired output: [Ok(1), Ok(11), Ok(1426), Ok(3)]
-fn list_of_results() -> Vec<Result<i32, DivisionError>> {
+fn list_of_results() -> () {
     let numbers = vec![27, 297, 38502, 81];
-    let division_results = numbers.into_iter().map(|n| divide(n, 27)).collect();
-    division_results
+    let division_results = numbers.into_iter().map(|n| divide(n, 27));
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 // iterators4.rs
 
+// I AM NOT DONE
+
 pub fn factorial(num: u64) -> u64 {
     // Complete this function to return the factorial of num
     // Do not use:
@@ -10,7 +12,6 @@ pub fn factorial(num: u64) -> u64 {
     // For an extra challenge, don't use:
     // - recursion
     // Execute `rustlings hint iterators4` for hints.
-    (1..=num).product()
 }
 
 #[cfg(test)]
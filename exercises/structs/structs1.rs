@@ -1,13 +1,13 @@
 // structs1.rs
 // Address all the TODOs to make the tests pass!
 
+// I AM NOT DONE
+
 struct ColorClassicStruct {
     // TODO: Something goes here
-    name: String,
-    hex: String,
 }
 
-struct ColorTupleStruct(/* TODO: Something goes here */ String, String);
+struct ColorTupleStruct(/* TODO: Something goes here */);
 
 #[derive(Debug)]
 struct UnitStruct;
@@ -19,10 +19,7 @@ mod tests {
     #[test]
     fn classic_c_structs() {
         // TODO: Instantiate a classic c struct!
-        let green = ColorClassicStruct {
-            name: String::from("green"),
-            hex: String::from("#00FF00"),
-        };
+        // let green =
 
         assert_eq!(green.name, "green");
         assert_eq!(green.hex, "#00FF00");
@@ -31,7 +28,7 @@ mod tests {
     #[test]
     fn tuple_structs() {
         // TODO: Instantiate a tuple struct!
-        let green = ColorTupleStruct(String::from("green"), String::from("#00FF00"));
+        // let green =
 
         assert_eq!(green.0, "green");
         assert_eq!(green.1, "#00FF00");
@@ -40,7 +37,7 @@ mod tests {
     #[test]
     fn unit_structs() {
         // TODO: Instantiate a unit struct!
-        let unit_struct = UnitStruct;
+        // let unit_struct =
         let message = format!("{:?}s are fun!", unit_struct);
 
         assert_eq!(message, "UnitStructs are fun!");
@@ -4,6 +4,8 @@
 // Make the code compile and the tests pass!
 // If you have issues execute `rustlings hint structs3`
 
+// I AM NOT DONE
+
 #[derive(Debug)]
 struct Package {
     sender_country: String,
@@ -15,7 +17,6 @@ impl Package {
     fn new(sender_country: String, recipient_country: String, weight_in_grams: i32) -> Package {
         if weight_in_grams <= 0 {
             // Something goes here...
-            panic!("Negative weight_in_grams");
         } else {
             return Package {
                 sender_country,
@@ -25,14 +26,12 @@ impl Package {
         }
     }
 
-    fn is_international(&self) -> bool {
+    fn is_international(&self) -> ??? {
         // Something goes here...
-        self.sender_country.ne(&(self.recipient_country))
     }
 
-    fn get_fees(&self, cents_per_gram: i32) -> i32 {
+    fn get_fees(&self, cents_per_gram: i32) -> ??? {
         // Something goes here...
-        self.weight_in_grams * cents_per_gram
     }
 }
 
@@ -74,7 +73,7 @@ mod tests {
         let sender_country = String::from("Spain");
         let recipient_country = String::from("Spain");
 
-        let cents_per_gram = 3;
+        let cents_per_gram = ???;
 
         let package = Package::new(sender_country, recipient_country, 1500);
 
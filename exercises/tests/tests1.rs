@@ -6,10 +6,12 @@
 // This test has a problem with it -- make the test compile! Make the test
 // pass! Make the test fail! Execute `rustlings hint tests1` for hints :)
 
+// I AM NOT DONE
+
 #[cfg(test)]
 mod tests {
     #[test]
     fn you_can_assert() {
-        assert!(true);
+        assert!();
     }
 }
@@ -2,10 +2,12 @@
 // This test has a problem with it -- make the test compile! Make the test
 // pass! Make the test fail! Execute `rustlings hint tests2` for hints :)
 
+// I AM NOT DONE
+
 #[cfg(test)]
 mod tests {
     #[test]
     fn you_can_assert_eq() {
-        assert_eq!(1 + 1, 2);
+        assert_eq!();
     }
 }
@@ -4,6 +4,8 @@
 // we expect to get when we call `is_even(5)`.
 // Execute `rustlings hint tests3` for hints :)
 
+// I AM NOT DONE
+
 pub fn is_even(num: i32) -> bool {
     num % 2 == 0
 }
@@ -14,11 +16,11 @@ mod tests {
 
     #[test]
     fn is_true_when_even() {
-        assert!(is_even(6));
+        assert!();
     }
 
     #[test]
     fn is_false_when_odd() {
-        assert!(!is_even(5));
+        assert!();
     }
 }
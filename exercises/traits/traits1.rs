@@ -8,15 +8,14 @@
 // which appends "Bar" to any object
 // implementing this trait.
 
+// I AM NOT DONE
+
 trait AppendBar {
     fn append_bar(self) -> Self;
 }
 
 impl AppendBar for String {
     //Add your code here
-    fn append_bar(self) -> Self {
-        [self, String::from("Bar")].join("")
-    }
 }
 
 fn main() {
@@ -6,7 +6,9 @@
 // even after you already figured it out. If you got everything working and
 // feel ready for the next exercise, remove the `I AM NOT DONE` comment below.
 
+// I AM NOT DONE
+
 fn main() {
-    let x = 5;
+    x = 5;
     println!("x has the value {}", x);
 }
@@ -1,8 +1,10 @@
 // variables2.rs
 // Make me compile! Execute the command `rustlings hint variables2` if you want a hint :)
 
+// I AM NOT DONE
+
 fn main() {
-    let x = 10;
+    let x;
     if x == 10 {
         println!("Ten!");
     } else {
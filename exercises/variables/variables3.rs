@@ -1,8 +1,10 @@
 // variables3.rs
 // Make me compile! Execute the command `rustlings hint variables3` if you want a hint :)
 
+// I AM NOT DONE
+
 fn main() {
-    let mut x = 3;
+    let x = 3;
     println!("Number {}", x);
     x = 5; // don't change this line
     println!("Number {}", x);
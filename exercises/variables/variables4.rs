@@ -1,7 +1,9 @@
 // variables4.rs
 // Make me compile! Execute the command `rustlings hint variables4` if you want a hint :)
 
+// I AM NOT DONE
+
 fn main() {
-    let x: i32 = 0;
+    let x: i32;
     println!("Number {}", x);
 }
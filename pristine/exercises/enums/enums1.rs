@@ -0,0 +1,16 @@
+// enums1.rs
+// Make me compile! Execute `rustlings hint enums1` for hints!
+
+// I AM NOT DONE
+
+#[derive(Debug)]
+enum Message {
+    // TODO: define a few types of messages as used below
+}
+
+fn main() {
+    println!("{:?}", Message::Quit);
+    println!("{:?}", Message::Echo);
+    println!("{:?}", Message::Move);
+    println!("{:?}", Message::ChangeColor);
+}
@@ -0,0 +1,11 @@
+// This shopping list program isn't compiling!
+// Use your knowledge of generics to fix it.
+
+// Execute `rustlings hint generics1` for hints!
+
+// I AM NOT DONE
+
+fn main() {
+    let mut shopping_list: Vec<?> = Vec::new();
+    shopping_list.push("milk");
+}
@@ -0,0 +1,14 @@
+// modules1.rs
+// Make me compile! Execute `rustlings hint modules1` for hints :)
+
+// I AM NOT DONE
+
+mod sausage_factory {
+    fn make_sausage() {
+        println!("sausage!");
+    }
+}
+
+fn main() {
+    sausage_factory::make_sausage();
+}
@@ -0,0 +1,13 @@
+// variables2.rs
+// Make me compile! Execute the command `rustlings hint variables2` if you want a hint :)
+
+// I AM NOT DONE
+
+fn main() {
+    let x;
+    if x == 10 {
+        println!("Ten!");
+    } else {
+        println!("Not ten!");
+    }
+}
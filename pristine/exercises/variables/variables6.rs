@@ -0,0 +1,9 @@
+// variables6.rs
+// Make me compile! Execute the command `rustlings hint variables6` if you want a hint :)
+
+// I AM NOT DONE
+
+const NUMBER = 3;
+fn main() {
+    println!("Number {}", NUMBER);
+}
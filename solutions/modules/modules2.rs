@@ -0,0 +1,25 @@
+// modules2.rs
+// Make me compile! Execute `rustlings hint modules2` for hints :)
+
+mod delicious_snacks {
+    use self::fruits::PEAR as fruit;
+    use self::veggies::CUCUMBER as veggie;
+
+    pub mod fruits {
+        pub const PEAR: &'static str = "Pear";
+        pub const APPLE: &'static str = "Apple";
+    }
+
+    pub mod veggies {
+        pub const CUCUMBER: &'static str = "Cucumber";
+        pub const CARROT: &'static str = "Carrot";
+    }
+}
+
+fn main() {
+    println!(
+        "favorite snacks: {} and {}",
+        delicious_snacks::fruits::PEAR,
+        delicious_snacks::veggies::CUCUMBER,
+    );
+}
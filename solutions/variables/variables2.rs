@@ -0,0 +1,11 @@
+// variables2.rs
+// Make me compile! Execute the command `rustlings hint variables2` if you want a hint :)
+
+fn main() {
+    let x = 10;
+    if x == 10 {
+        println!("Ten!");
+    } else {
+        println!("Not ten!");
+    }
+}
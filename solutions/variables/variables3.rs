@@ -0,0 +1,9 @@
+// variables3.rs
+// Make me compile! Execute the command `rustlings hint variables3` if you want a hint :)
+
+fn main() {
+    let mut x = 3;
+    println!("Number {}", x);
+    x = 5; // don't change this line
+    println!("Number {}", x);
+}
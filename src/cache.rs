@@ -0,0 +1,304 @@
+use crate::diagnostics::Diagnostic;
+use crate::exercise::{fnv1a, Exercise, ExerciseOutput, Mode};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+// Where compilations are cached, relative to the rustlings directory
+pub const CACHE_DIR: &str = "target/rustlings-cache";
+// Bumped whenever the way exercises are compiled changes, so that results
+// of older versions of rustlings aren't used anymore
+const CACHE_VERSION: &str = "4";
+// Where the explanations of error codes are kept, within the cache directory
+pub const EXPLANATIONS_DIR: &str = "explanations";
+
+static TEMP_ENTRY_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+// What is stored about a compilation next to its binaries
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    // The key of the compilation, an exercise only keeps its latest one
+    key: String,
+    success: bool,
+    stdout: String,
+    stderr: String,
+    #[serde(default)]
+    diagnostics: Vec<Diagnostic>,
+    // How many binaries were built, stored as `0`, `1`, ...
+    binaries: usize,
+}
+
+// The output of `rustc -vV`, which tells apart toolchains down to the commit
+pub fn rustc_version() -> &'static str {
+    static VERSION: OnceLock<String> = OnceLock::new();
+    VERSION.get_or_init(|| {
+        Command::new("rustc")
+            .arg("-vV")
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+            .unwrap_or_default()
+    })
+}
+
+// The key a compilation of the exercise is cached under. It covers
+// everything the outcome depends on: the sources, which are hashed with the
+// `I AM NOT DONE` marker as it shifts the lines of the compiler's messages,
+// the path, the mode, the flags the compiler is run with and the toolchain.
+// For cargo exercises that includes the Cargo.lock of the crate and the
+// checksums of the vendored crates.
+pub fn key(exercise: &Exercise, sources: &[String], flags: &[String]) -> String {
+    let mode = format!("{:?}", exercise.mode);
+    let path = exercise.path.display().to_string();
+    let parts = [CACHE_VERSION, &mode, &path, rustc_version()];
+    let mut input = Vec::new();
+    for part in parts
+        .iter()
+        .copied()
+        .chain(flags.iter().map(String::as_str))
+    {
+        input.extend_from_slice(part.as_bytes());
+        input.push(0);
+    }
+    for source in sources.iter().chain(&dependencies(exercise)) {
+        input.extend_from_slice(source.as_bytes());
+        input.push(0);
+    }
+    format!("{:016x}", fnv1a(input))
+}
+
+// The Cargo.lock of a cargo exercise and the checksums of the vendored crates
+fn dependencies(exercise: &Exercise) -> Vec<String> {
+    if exercise.mode != Mode::Cargo {
+        return Vec::new();
+    }
+    let mut files = vec![exercise.path.join("Cargo.lock")];
+    if let Ok(entries) = fs::read_dir(exercise.vendor_dir()) {
+        let mut crates: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path().join(".cargo-checksum.json"))
+            .collect();
+        crates.sort();
+        files.extend(crates);
+    }
+    files
+        .iter()
+        .map(|file| fs::read_to_string(file).unwrap_or_default())
+        .collect()
+}
+
+// The directory the compilation of the exercise is kept in
+fn entry_dir(dir: &Path, exercise: &Exercise) -> PathBuf {
+    // The names of pack exercises contain a `/`
+    dir.join(exercise.name.replace('/', "_"))
+}
+
+// The result of the last compilation of the exercise if it had the same key,
+// with the paths of its binaries if it succeeded. `None` if there is none, or
+// it can't be read.
+pub fn load(
+    dir: &Path,
+    exercise: &Exercise,
+    key: &str,
+) -> Option<Result<Vec<String>, ExerciseOutput>> {
+    let entry_dir = entry_dir(dir, exercise);
+    let entry: Entry =
+        serde_json::from_str(&fs::read_to_string(entry_dir.join("entry.json")).ok()?).ok()?;
+    if entry.key != key {
+        return None;
+    }
+    if !entry.success {
+        return Some(Err(ExerciseOutput {
+            stdout: entry.stdout,
+            stderr: entry.stderr,
+            diagnostics: entry.diagnostics,
+            ..ExerciseOutput::default()
+        }));
+    }
+    // Absolute paths, as cargo exercises are run in the directory of their crate
+    let entry_dir = fs::canonicalize(entry_dir).ok()?;
+    let binaries: Vec<String> = (0..entry.binaries)
+        .map(|i| entry_dir.join(i.to_string()).display().to_string())
+        .collect();
+    if binaries.iter().all(|binary| Path::new(binary).is_file()) {
+        Some(Ok(binaries))
+    } else {
+        None
+    }
+}
+
+// Store the result of a compilation, along with its binaries, in place of
+// the earlier compilation of the exercise. The entry is put together under a
+// name of its own first, so that a concurrent `load` never sees half of it.
+pub fn store(
+    dir: &Path,
+    exercise: &Exercise,
+    key: &str,
+    result: &Result<Vec<String>, ExerciseOutput>,
+) -> io::Result<()> {
+    let entry_dir = entry_dir(dir, exercise);
+    let id = TEMP_ENTRY_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let mut temp_name = entry_dir.as_os_str().to_owned();
+    temp_name.push(format!(".{}.{}.tmp", process::id(), id));
+    let temp_dir = PathBuf::from(temp_name);
+    fs::create_dir_all(&temp_dir)?;
+    let stored = write_entry(&temp_dir, key, result).and_then(|()| {
+        let _ignored = fs::remove_dir_all(&entry_dir);
+        fs::rename(&temp_dir, &entry_dir)
+    });
+    if stored.is_err() {
+        let _ignored = fs::remove_dir_all(&temp_dir);
+    }
+    stored
+}
+
+fn write_entry(
+    entry_dir: &Path,
+    key: &str,
+    result: &Result<Vec<String>, ExerciseOutput>,
+) -> io::Result<()> {
+    let entry = match result {
+        Ok(binaries) => {
+            for (i, binary) in binaries.iter().enumerate() {
+                fs::copy(binary, entry_dir.join(i.to_string()))?;
+            }
+            Entry {
+                key: key.to_string(),
+                success: true,
+                stdout: String::new(),
+                stderr: String::new(),
+                diagnostics: Vec::new(),
+                binaries: binaries.len(),
+            }
+        }
+        Err(output) => Entry {
+            key: key.to_string(),
+            success: false,
+            stdout: output.stdout.clone(),
+            stderr: output.stderr.clone(),
+            diagnostics: output.diagnostics.clone(),
+            binaries: 0,
+        },
+    };
+    let json =
+        serde_json::to_string(&entry).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    fs::write(entry_dir.join("entry.json"), json)
+}
+
+// Remove every cached compilation, and the explanations kept along with
+// them, returning how many compilations there were
+pub fn clean(dir: &Path) -> io::Result<usize> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .flatten()
+            .filter(|entry| entry.file_name() != EXPLANATIONS_DIR)
+            .count(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+    fs::remove_dir_all(dir)?;
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::diagnostics;
+    use crate::exercise::{Limits, Lints};
+    use crate::pack::Pack;
+
+    fn exercise(name: &str, path: &str, mode: Mode) -> Exercise {
+        Exercise {
+            name: String::from(name),
+            path: PathBuf::from(path),
+            solution: None,
+            mode,
+            topic: None,
+            hint: String::new(),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: None,
+            pack: None,
+        }
+    }
+
+    fn failure(stderr: &str) -> Result<Vec<String>, ExerciseOutput> {
+        Err(ExerciseOutput {
+            stderr: String::from(stderr),
+            diagnostics: diagnostics::parse(
+                r#"{"message":"cannot find value `x` in this scope","code":{"code":"E0425"},"level":"error"}"#,
+                Path::new(""),
+            )
+            .0,
+            ..ExerciseOutput::default()
+        })
+    }
+
+    #[test]
+    fn test_store_and_load() {
+        let dir = PathBuf::from("target/test-cache");
+        let _ = fs::remove_dir_all(&dir);
+        let exercise = exercise(
+            "example",
+            "tests/fixture/state/pending_exercise.rs",
+            Mode::Compile,
+        );
+        let sources = exercise.sources();
+        let key = key(&exercise, &sources, &[]);
+        assert_ne!(
+            key,
+            super::key(&exercise, &sources, &[String::from("--test")])
+        );
+        assert!(load(&dir, &exercise, &key).is_none());
+
+        store(&dir, &exercise, &key, &failure("error[E0425]")).unwrap();
+        let cached = load(&dir, &exercise, &key).unwrap().unwrap_err();
+        assert_eq!(cached.stderr, "error[E0425]");
+        assert_eq!(cached.codes(), vec!["E0425"]);
+
+        // A newer compilation of the exercise takes the place of the older one
+        let flags = [String::from("--test")];
+        let newer = super::key(&exercise, &sources, &flags);
+        store(&dir, &exercise, &newer, &failure("newer")).unwrap();
+        assert!(load(&dir, &exercise, &key).is_none());
+        assert!(load(&dir, &exercise, &newer).is_some());
+
+        assert_eq!(clean(&dir).unwrap(), 1);
+        assert!(load(&dir, &exercise, &newer).is_none());
+    }
+
+    #[test]
+    fn test_cargo_key() {
+        let dir = PathBuf::from("target/test-cache-key");
+        let _ = fs::remove_dir_all(&dir);
+        let checksum = dir.join("vendor/greeter/.cargo-checksum.json");
+        fs::create_dir_all(checksum.parent().unwrap()).unwrap();
+        fs::create_dir_all(dir.join("exercises/hello/src")).unwrap();
+        fs::write(dir.join("exercises/hello/src/lib.rs"), "").unwrap();
+        let mut exercise = exercise(
+            "pack/hello",
+            "target/test-cache-key/exercises/hello",
+            Mode::Cargo,
+        );
+        exercise.pack = Some(Pack {
+            name: String::from("pack"),
+            dir: dir.clone(),
+        });
+        let sources = exercise.sources();
+        let mut keys = vec![key(&exercise, &sources, &[])];
+
+        fs::write(dir.join("exercises/hello/Cargo.lock"), "version = 3").unwrap();
+        keys.push(key(&exercise, &sources, &[]));
+        fs::write(&checksum, r#"{"package":"1"}"#).unwrap();
+        keys.push(key(&exercise, &sources, &[]));
+        fs::write(&checksum, r#"{"package":"2"}"#).unwrap();
+        keys.push(key(&exercise, &sources, &[]));
+
+        keys.dedup();
+        assert_eq!(keys.len(), 4);
+    }
+}
@@ -0,0 +1,478 @@
+use crate::pack::CONFIG_FILE;
+use console::StyledObject;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use toml::value::{Table, Value};
+
+// The file in the user's config directory with the preferences for every
+// rustlings directory
+const USER_CONFIG_FILE: &str = "rustlings/config.toml";
+// What the names of the environment variables preferences are set with start with
+const ENV_PREFIX: &str = "RUSTLINGS_";
+
+// Every preference, with what it is about for `config`
+pub const KEYS: &[(&str, &str)] = &[
+    ("emoji", "show emoji in messages: true or false"),
+    (
+        "color",
+        "color the output: auto (in a terminal), always or never",
+    ),
+    ("verbose", "show the output of passing tests: true or false"),
+    (
+        "editor",
+        "the command the terminal interface opens exercises with",
+    ),
+    (
+        "timeout",
+        "stop exercise binaries that set no timeout after this many seconds, 0 disables it",
+    ),
+    (
+        "rustc_flags",
+        "more flags to compile exercises with, separated by spaces",
+    ),
+    (
+        "theme",
+        "the colors that suit the terminal's background: dark or light",
+    ),
+];
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    // Whether the output is colored, which it is in a terminal that can show
+    // colors with `auto`
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorMode::Auto => {
+                io::stdout().is_terminal()
+                    && env::var_os("TERM").map_or(true, |term| term != "dumb")
+            }
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
+
+    // The flags rustc and cargo are run with. Their output is captured, so
+    // they are told whether to color it instead of finding out themselves.
+    pub fn compiler_args(self) -> Vec<String> {
+        let when = if self.enabled() { "always" } else { "never" };
+        vec![String::from("--color"), String::from(when)]
+    }
+}
+
+impl FromStr for ColorMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorMode::Auto),
+            "always" => Ok(ColorMode::Always),
+            "never" => Ok(ColorMode::Never),
+            _ => Err(format!(
+                "unknown color mode '{}', expected one of 'auto', 'always' or 'never'",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Dark,
+    Light,
+}
+
+impl Theme {
+    // Highlight code, the headings of diffs and the like in the color that
+    // is readable on the terminal's background
+    pub fn accent<D>(self, text: StyledObject<D>) -> StyledObject<D> {
+        match self {
+            Theme::Dark => text.cyan(),
+            Theme::Light => text.blue(),
+        }
+    }
+}
+
+// The preferences that are set in one place: on the command line, in the
+// environment or in a config file
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+pub struct Layer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<ColorMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbose: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rustc_flags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<Theme>,
+}
+
+impl Layer {
+    // Take every preference this layer doesn't set from `other`
+    fn or(self, other: Layer) -> Layer {
+        Layer {
+            emoji: self.emoji.or(other.emoji),
+            color: self.color.or(other.color),
+            verbose: self.verbose.or(other.verbose),
+            editor: self.editor.or(other.editor),
+            timeout: self.timeout.or(other.timeout),
+            rustc_flags: self.rustc_flags.or(other.rustc_flags),
+            theme: self.theme.or(other.theme),
+        }
+    }
+
+    fn table(&self) -> Table {
+        match Value::try_from(self) {
+            Ok(Value::Table(table)) => table,
+            _ => Table::new(),
+        }
+    }
+
+    // A layer with only the given preference set, which fails if the value
+    // doesn't suit the preference, like `color = "blue"`
+    fn with(key: &str, value: Value) -> Result<Layer, String> {
+        let mut table = Table::new();
+        table.insert(key.to_string(), value);
+        Value::Table(table)
+            .try_into()
+            .map_err(|e| format!("Invalid value for `{}`: {}", key, e))
+    }
+}
+
+// The preferences that apply
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub emoji: bool,
+    pub color: ColorMode,
+    pub verbose: bool,
+    pub editor: String,
+    // The timeout of the exercises that don't set one, if set
+    pub timeout: Option<u64>,
+    pub rustc_flags: Vec<String>,
+    pub theme: Theme,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            emoji: true,
+            color: ColorMode::Auto,
+            verbose: false,
+            editor: env::var("VISUAL")
+                .or_else(|_| env::var("EDITOR"))
+                .unwrap_or_else(|_| String::from("vi")),
+            timeout: None,
+            rustc_flags: Vec::new(),
+            theme: Theme::Dark,
+        }
+    }
+}
+
+impl Config {
+    fn layer(&self) -> Layer {
+        Layer {
+            emoji: Some(self.emoji),
+            color: Some(self.color),
+            verbose: Some(self.verbose),
+            editor: Some(self.editor.clone()),
+            timeout: self.timeout,
+            rustc_flags: Some(self.rustc_flags.clone()),
+            theme: Some(self.theme),
+        }
+    }
+}
+
+// Where a preference is set
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    CommandLine,
+    Environment(String),
+    File(PathBuf),
+    Default,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Source::CommandLine => write!(f, "command line"),
+            Source::Environment(variable) => write!(f, "${}", variable),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Default => write!(f, "default"),
+        }
+    }
+}
+
+// Every place preferences are set in, from the one that wins to the one that
+// loses: the command line, the environment, rustlings.toml in the rustlings
+// directory and the user's config file
+pub struct Layers(Vec<(Source, Layer)>);
+
+impl Layers {
+    pub fn load(command_line: Layer) -> Result<Layers, String> {
+        let mut layers = vec![(Source::CommandLine, command_line)];
+        layers.extend(environment()?);
+        for path in Some(PathBuf::from(CONFIG_FILE))
+            .into_iter()
+            .chain(user_config_path())
+        {
+            if let Some(layer) = read(&path)? {
+                layers.push((Source::File(path), layer));
+            }
+        }
+        Ok(Layers(layers))
+    }
+
+    pub fn resolve(&self) -> Config {
+        let layer = self.0.iter().fold(Layer::default(), |layer, (_, other)| {
+            layer.or(other.clone())
+        });
+        let default = Config::default();
+        Config {
+            emoji: layer.emoji.unwrap_or(default.emoji),
+            color: layer.color.unwrap_or(default.color),
+            verbose: layer.verbose.unwrap_or(default.verbose),
+            editor: layer.editor.unwrap_or(default.editor),
+            timeout: layer.timeout.or(default.timeout),
+            rustc_flags: layer.rustc_flags.unwrap_or(default.rustc_flags),
+            theme: layer.theme.unwrap_or(default.theme),
+        }
+    }
+
+    // The value of the preference that applies and where it is set
+    pub fn lookup(&self, key: &str) -> Option<(Value, Source)> {
+        self.0
+            .iter()
+            .find_map(|(source, layer)| {
+                layer
+                    .table()
+                    .remove(key)
+                    .map(|value| (value, source.clone()))
+            })
+            .or_else(|| {
+                let value = self.resolve().layer().table().remove(key)?;
+                Some((value, Source::Default))
+            })
+    }
+}
+
+// The preferences set with `RUSTLINGS_<KEY>` environment variables, along
+// with `NO_EMOJI`, which rustlings has always known, and `NO_COLOR`, which
+// counts when it isn't empty (https://no-color.org)
+fn environment() -> Result<Vec<(Source, Layer)>, String> {
+    let mut layers = Vec::new();
+    for (key, _) in KEYS {
+        let variable = format!("{}{}", ENV_PREFIX, key.to_uppercase());
+        if let Ok(value) = env::var(&variable) {
+            let layer = Layer::with(key, parse_value(key, &value)?)?;
+            layers.push((Source::Environment(variable), layer));
+        }
+    }
+    if env::var_os("NO_EMOJI").is_some() {
+        let layer = Layer {
+            emoji: Some(false),
+            ..Layer::default()
+        };
+        layers.push((Source::Environment(String::from("NO_EMOJI")), layer));
+    }
+    if env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        let layer = Layer {
+            color: Some(ColorMode::Never),
+            ..Layer::default()
+        };
+        layers.push((Source::Environment(String::from("NO_COLOR")), layer));
+    }
+    Ok(layers)
+}
+
+// The preferences in a config file, `None` if there is no such file
+fn read(path: &Path) -> Result<Option<Layer>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents)
+            .map(Some)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Failed to read {}: {}", path.display(), e)),
+    }
+}
+
+// The config file of the user, following the XDG base directory
+// specification: `$XDG_CONFIG_HOME/rustlings/config.toml`, which is
+// `~/.config/rustlings/config.toml` unless set otherwise
+pub fn user_config_path() -> Option<PathBuf> {
+    let dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from);
+    #[cfg(windows)]
+    let dir = dir.or_else(|| env::var_os("APPDATA").map(PathBuf::from));
+    let dir =
+        dir.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(dir.join(USER_CONFIG_FILE))
+}
+
+// Turn the value of a preference as it is typed on the command line, or set
+// in the environment, into the value for a config file
+fn parse_value(key: &str, value: &str) -> Result<Value, String> {
+    let parsed = match key {
+        "emoji" | "verbose" => value
+            .parse()
+            .map(Value::Boolean)
+            .map_err(|_| format!("`{}` has to be true or false", key))?,
+        "timeout" => value
+            .parse::<u32>()
+            .map(|secs| Value::Integer(i64::from(secs)))
+            .map_err(|_| format!("`{}` has to be a number of seconds", key))?,
+        "rustc_flags" => Value::Array(
+            value
+                .split_whitespace()
+                .map(|flag| Value::String(flag.to_string()))
+                .collect(),
+        ),
+        "color" | "editor" | "theme" => Value::String(value.to_string()),
+        _ => {
+            return Err(format!(
+                "There is no preference called `{}`, `rustlings config get` lists them all.",
+                key
+            ))
+        }
+    };
+    Layer::with(key, parsed.clone())?;
+    Ok(parsed)
+}
+
+// Set the preference in the config file, keeping everything else in it
+pub fn set(path: &Path, key: &str, value: &str) -> Result<(), String> {
+    let value = parse_value(key, value)?;
+    let mut table = match fs::read_to_string(path) {
+        Ok(contents) => match contents.parse::<Value>() {
+            Ok(Value::Table(table)) => table,
+            Ok(_) => Table::new(),
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+        },
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Table::new(),
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+    table.insert(key.to_string(), value);
+    let contents = toml::to_string(&Value::Table(table)).map_err(|e| e.to_string())?;
+    path.parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, contents))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+// Make the preferences apply, once when rustlings starts. Everything styled
+// with `console` follows the color preference from then on, like the
+// messages of `success!` and `warn!`.
+pub fn init(config: Config) {
+    console::set_colors_enabled(config.color.enabled());
+    let _ = CONFIG.set(config);
+}
+
+// The preferences that apply, the defaults until `init` was called
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("emoji", "false"), Ok(Value::Boolean(false)));
+        assert_eq!(parse_value("timeout", "30"), Ok(Value::Integer(30)));
+        assert_eq!(
+            parse_value("rustc_flags", "-C  opt-level=1"),
+            Ok(Value::Array(vec![
+                Value::String(String::from("-C")),
+                Value::String(String::from("opt-level=1"))
+            ]))
+        );
+        assert!(parse_value("emoji", "maybe").is_err());
+        assert!(parse_value("timeout", "-1").is_err());
+        assert!(parse_value("color", "blue").is_err());
+        assert!(parse_value("colour", "auto").is_err());
+    }
+
+    #[test]
+    fn test_precedence() {
+        let file = PathBuf::from("rustlings.toml");
+        let layers = Layers(vec![
+            (
+                Source::CommandLine,
+                Layer {
+                    timeout: Some(5),
+                    ..Layer::default()
+                },
+            ),
+            (
+                Source::Environment(String::from("NO_EMOJI")),
+                Layer {
+                    emoji: Some(false),
+                    ..Layer::default()
+                },
+            ),
+            (
+                Source::File(file.clone()),
+                Layer {
+                    emoji: Some(true),
+                    timeout: Some(60),
+                    theme: Some(Theme::Light),
+                    ..Layer::default()
+                },
+            ),
+        ]);
+        let config = layers.resolve();
+        assert_eq!(config.timeout, Some(5));
+        assert!(!config.emoji);
+        assert_eq!(config.theme, Theme::Light);
+        assert_eq!(config.color, ColorMode::Auto);
+        assert_eq!(
+            layers.lookup("theme"),
+            Some((Value::String(String::from("light")), Source::File(file)))
+        );
+        assert_eq!(
+            layers.lookup("color"),
+            Some((Value::String(String::from("auto")), Source::Default))
+        );
+    }
+
+    #[test]
+    fn test_set_keeps_the_rest() {
+        let path = PathBuf::from("target/test-config/rustlings.toml");
+        let _ = fs::remove_dir_all("target/test-config");
+        fs::create_dir_all("target/test-config").unwrap();
+        fs::write(&path, "packs = [\"packs/async\"]\n").unwrap();
+        set(&path, "color", "never").unwrap();
+        set(&path, "timeout", "20").unwrap();
+        assert!(set(&path, "theme", "pink").is_err());
+        let layer = read(&path).unwrap().unwrap();
+        assert_eq!(layer.color, Some(ColorMode::Never));
+        assert_eq!(layer.timeout, Some(20));
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("packs = [\"packs/async\"]"));
+        fs::remove_dir_all("target/test-config").unwrap();
+    }
+}
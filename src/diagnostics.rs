@@ -0,0 +1,399 @@
+use console::style;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+
+// A message of the compiler or clippy, as they emit it with
+// `--error-format=json` and `--message-format json`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<Code>,
+    pub level: Level,
+    #[serde(default)]
+    pub spans: Vec<Span>,
+    // Notes, help and suggestions that go with the message
+    #[serde(default)]
+    pub children: Vec<Diagnostic>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Code {
+    // An error code like `E0382`, or the name of a lint like `clippy::needless_return`
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+    Help,
+    FailureNote,
+    #[serde(rename = "error: internal compiler error")]
+    InternalCompilerError,
+    #[serde(other)]
+    Other,
+}
+
+// A piece of code a diagnostic points at
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Span {
+    pub file_name: String,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    // The lines of code the span covers
+    #[serde(default)]
+    pub text: Vec<SpanLine>,
+    pub label: Option<String>,
+    // What the compiler suggests to put in place of the span
+    pub suggested_replacement: Option<String>,
+    pub suggestion_applicability: Option<Applicability>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SpanLine {
+    pub text: String,
+    // The columns of the span on the line, counted from 1
+    pub highlight_start: usize,
+    pub highlight_end: usize,
+}
+
+// How sure the compiler is that a suggestion is right
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub enum Applicability {
+    MachineApplicable,
+    MaybeIncorrect,
+    HasPlaceholders,
+    Unspecified,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+            Level::Help => "help",
+            Level::FailureNote => "failure-note",
+            Level::InternalCompilerError => "internal compiler error",
+            Level::Other => "message",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Diagnostic {
+    fn is_error(&self) -> bool {
+        matches!(self.level, Level::Error | Level::InternalCompilerError)
+    }
+
+    // Whether this is one of the messages the compiler ends with, like
+    // `aborting due to 2 previous errors`, rather than one about the code
+    fn is_summary(&self) -> bool {
+        self.spans.is_empty()
+            && (self.level == Level::FailureNote
+                || self.message.starts_with("aborting due to")
+                || self.message.ends_with(" emitted"))
+    }
+
+    fn primary_span(&self) -> Option<&Span> {
+        self.spans.iter().find(|span| span.is_primary)
+    }
+}
+
+// Whether the code is an error code like `E0382`, rather than the name of a lint
+fn is_error_code(code: &str) -> bool {
+    code.strip_prefix('E')
+        .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+}
+
+// Split the output of the compiler or cargo in their JSON formats into the
+// diagnostics in it, without duplicates, and the lines that aren't JSON, e.g.
+// those of a compiler that crashed. The file names of the diagnostics are
+// made relative to the current directory where they can be, taking relative
+// ones as relative to `base`, the directory the compiler was run in.
+pub fn parse(output: &str, base: &Path) -> (Vec<Diagnostic>, String) {
+    let current_dir = env::current_dir().unwrap_or_default();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut other = String::new();
+    for line in output.lines() {
+        let value: serde_json::Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(_) => {
+                other.push_str(line);
+                other.push('\n');
+                continue;
+            }
+        };
+        // Cargo wraps the diagnostics into messages of its own, among ones
+        // about the artifacts it built
+        let message = match value.get("reason") {
+            Some(reason) if reason == "compiler-message" => value["message"].clone(),
+            Some(_) => continue,
+            None => value,
+        };
+        if let Ok(mut diagnostic) = serde_json::from_value::<Diagnostic>(message) {
+            relocate(&mut diagnostic, base, &current_dir);
+            // Cargo builds a crate with tests once for its library and
+            // once for its tests, with the same diagnostics for both
+            if !diagnostics.contains(&diagnostic) {
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+    (diagnostics, other)
+}
+
+fn relocate(diagnostic: &mut Diagnostic, base: &Path, current_dir: &Path) {
+    for span in &mut diagnostic.spans {
+        let path = base.join(&span.file_name);
+        let path = path.strip_prefix(current_dir).unwrap_or(&path);
+        span.file_name = path.display().to_string();
+    }
+    for child in &mut diagnostic.children {
+        relocate(child, base, current_dir);
+    }
+}
+
+// The error codes of the errors, like `E0382`, in the order they came up in
+pub fn error_codes(diagnostics: &[Diagnostic]) -> Vec<String> {
+    codes(diagnostics, true)
+}
+
+// The lints that were denied, like `clippy::needless_return`, leaving out the
+// ones that only warned
+pub fn lints(diagnostics: &[Diagnostic]) -> Vec<String> {
+    codes(diagnostics, false)
+}
+
+fn codes(diagnostics: &[Diagnostic], errors: bool) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    for diagnostic in diagnostics {
+        if let (true, Some(Code { code })) = (diagnostic.is_error(), &diagnostic.code) {
+            if is_error_code(code) == errors && !codes.contains(code) {
+                codes.push(code.clone());
+            }
+        }
+    }
+    codes
+}
+
+// Render the diagnostics in a shorter form than the compiler does: only the
+// line of code each of them is about, with what else the compiler has to say
+// as notes underneath, and the suggestions spelled out. Warnings are left out
+// when there are errors, as are the summaries the compiler ends with.
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let has_errors = diagnostics.iter().any(Diagnostic::is_error);
+    let mut out = String::new();
+    let mut hidden_warnings = 0;
+    for diagnostic in diagnostics.iter().filter(|d| !d.is_summary()) {
+        if has_errors && diagnostic.level == Level::Warning {
+            hidden_warnings += 1;
+            continue;
+        }
+        render_diagnostic(diagnostic, &mut out);
+        out.push('\n');
+    }
+    if hidden_warnings > 0 {
+        let plural = if hidden_warnings == 1 { "" } else { "s" };
+        out.push_str(&format!(
+            "{}\n",
+            style(format!(
+                "({} warning{} not shown until the errors are fixed)",
+                hidden_warnings, plural
+            ))
+            .dim()
+        ));
+    }
+    out
+}
+
+fn render_diagnostic(diagnostic: &Diagnostic, out: &mut String) {
+    let level = match &diagnostic.code {
+        Some(Code { code }) => format!("{}[{}]", diagnostic.level, code),
+        None => diagnostic.level.to_string(),
+    };
+    let level = match diagnostic.level {
+        Level::Error | Level::InternalCompilerError => style(level).red().bold(),
+        Level::Warning => style(level).yellow().bold(),
+        _ => style(level).bold(),
+    };
+    out.push_str(&format!(
+        "{}{}\n",
+        level,
+        style(format!(": {}", diagnostic.message)).bold()
+    ));
+
+    let primary = diagnostic.primary_span();
+    let gutter = primary.map_or(0, |span| span.line_start.to_string().len());
+    let bar = style(format!("{} |", " ".repeat(gutter))).blue().bold();
+    if let Some(span) = primary {
+        out.push_str(&format!(
+            "{}{} {}:{}:{}\n",
+            " ".repeat(gutter),
+            style("-->").blue().bold(),
+            span.file_name,
+            span.line_start,
+            span.column_start
+        ));
+        if let Some(line) = span.text.first() {
+            let start = line.highlight_start.max(1) - 1;
+            let width = line
+                .highlight_end
+                .saturating_sub(line.highlight_start)
+                .max(1);
+            let marker = format!(
+                "{}{} {}",
+                " ".repeat(start),
+                "^".repeat(width),
+                span.label.as_deref().unwrap_or_default()
+            );
+            let marker = if diagnostic.level == Level::Warning {
+                style(marker.trim_end().to_string()).yellow().bold()
+            } else {
+                style(marker.trim_end().to_string()).red().bold()
+            };
+            out.push_str(&format!("{}\n", bar));
+            out.push_str(&format!(
+                "{} {}\n",
+                style(format!("{} |", span.line_start)).blue().bold(),
+                line.text
+            ));
+            out.push_str(&format!("{} {}\n", bar, marker));
+        }
+    }
+
+    let mut notes = Vec::new();
+    for span in diagnostic.spans.iter().filter(|span| !span.is_primary) {
+        if let Some(label) = &span.label {
+            notes.push((Level::Note, format!("{} (line {})", label, span.line_start)));
+        }
+    }
+    for child in &diagnostic.children {
+        let replacements: Vec<String> = child
+            .spans
+            .iter()
+            .filter_map(|span| describe_replacement(span, span.suggested_replacement.as_deref()?))
+            .collect();
+        if replacements.is_empty() {
+            notes.push((child.level, child.message.clone()));
+        } else {
+            notes.push((
+                child.level,
+                format!("{}: {}", child.message, replacements.join(", ")),
+            ));
+        }
+    }
+    for (level, note) in notes {
+        out.push_str(&format!(
+            "{} {} {}\n",
+            style(format!("{} =", " ".repeat(gutter))).blue().bold(),
+            style(format!("{}:", level)).bold(),
+            note
+        ));
+    }
+}
+
+// How a suggested replacement reads in a note, e.g. `.clone()`. A suggestion
+// that replaces the span with nothing is described by what it removes, and
+// left out if that is only whitespace.
+fn describe_replacement(span: &Span, replacement: &str) -> Option<String> {
+    if !replacement.trim().is_empty() {
+        return Some(format!("`{}`", replacement.trim()));
+    }
+    if span.line_start != span.line_end {
+        return Some(format!(
+            "remove lines {} to {}",
+            span.line_start, span.line_end
+        ));
+    }
+    let line = span.text.first()?;
+    let removed: String = line
+        .text
+        .chars()
+        .skip(line.highlight_start.saturating_sub(1))
+        .take(line.highlight_end.saturating_sub(line.highlight_start))
+        .collect();
+    match removed.trim() {
+        "" => None,
+        removed => Some(format!("remove `{}`", removed)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use console::strip_ansi_codes;
+
+    const MOVED: &str = r#"{"$message_type":"diagnostic","message":"borrow of moved value: `vec0`","code":{"code":"E0382","explanation":"A variable was used after its contents have been moved elsewhere.\n"},"level":"error","spans":[{"file_name":"exercises/move_semantics/move_semantics1.rs","byte_start":200,"byte_end":204,"line_start":13,"line_end":13,"column_start":5,"column_end":9,"is_primary":true,"text":[{"text":"    vec0.push(88);","highlight_start":5,"highlight_end":9}],"label":"value borrowed here after move","suggested_replacement":null,"suggestion_applicability":null,"expansion":null},{"file_name":"exercises/move_semantics/move_semantics1.rs","byte_start":150,"byte_end":154,"line_start":10,"line_end":10,"column_start":30,"column_end":34,"is_primary":false,"text":[{"text":"    let vec1 = fill_vec(vec0);","highlight_start":25,"highlight_end":29}],"label":"value moved here","suggested_replacement":null,"suggestion_applicability":null,"expansion":null}],"children":[{"message":"consider cloning the value if the performance cost is acceptable","code":null,"level":"help","spans":[{"file_name":"exercises/move_semantics/move_semantics1.rs","byte_start":154,"byte_end":154,"line_start":10,"line_end":10,"column_start":34,"column_end":34,"is_primary":true,"text":[],"label":null,"suggested_replacement":".clone()","suggestion_applicability":"MachineApplicable","expansion":null}],"children":[],"rendered":null}],"rendered":"error[E0382]: borrow of moved value: `vec0`\n"}"#;
+    const UNUSED: &str = r#"{"$message_type":"diagnostic","message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[],"children":[],"rendered":"warning: unused variable: `x`\n"}"#;
+    const ABORTING: &str = r#"{"$message_type":"diagnostic","message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"children":[],"rendered":"error: aborting due to 1 previous error\n\n"}"#;
+
+    #[test]
+    fn test_parse() {
+        let output = format!(
+            "{}\n{{\"reason\":\"compiler-message\",\"message\":{}}}\n{{\"reason\":\"build-finished\",\"success\":false}}\n{}\nthread 'rustc' panicked\n",
+            MOVED, UNUSED, MOVED
+        );
+        let (diagnostics, other) = parse(&output, Path::new(""));
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(other, "thread 'rustc' panicked\n");
+        assert_eq!(error_codes(&diagnostics), vec!["E0382"]);
+        assert!(lints(&diagnostics).is_empty());
+        let suggestion = &diagnostics[0].children[0].spans[0];
+        assert_eq!(
+            suggestion.suggested_replacement.as_deref(),
+            Some(".clone()")
+        );
+        assert_eq!(
+            suggestion.suggestion_applicability,
+            Some(Applicability::MachineApplicable)
+        );
+
+        let (diagnostics, _) = parse(MOVED, Path::new("exercises"));
+        assert_eq!(
+            diagnostics[0].spans[0].file_name,
+            "exercises/exercises/move_semantics/move_semantics1.rs"
+        );
+    }
+
+    #[test]
+    fn test_render_removal() {
+        let unused_mut = r#"{"message":"variable does not need to be mutable","code":{"code":"unused_mut","explanation":null},"level":"warning","spans":[{"file_name":"exercises/variables/variables3.rs","byte_start":40,"byte_end":45,"line_start":4,"line_end":4,"column_start":9,"column_end":14,"is_primary":true,"text":[{"text":"    let mut x = 5;","highlight_start":9,"highlight_end":14}],"label":null,"suggested_replacement":null,"suggestion_applicability":null,"expansion":null}],"children":[{"message":"remove this `mut`","code":null,"level":"help","spans":[{"file_name":"exercises/variables/variables3.rs","byte_start":40,"byte_end":44,"line_start":4,"line_end":4,"column_start":9,"column_end":13,"is_primary":true,"text":[{"text":"    let mut x = 5;","highlight_start":9,"highlight_end":13}],"label":null,"suggested_replacement":"","suggestion_applicability":"MachineApplicable","expansion":null},{"file_name":"exercises/variables/variables3.rs","byte_start":45,"byte_end":46,"line_start":4,"line_end":4,"column_start":14,"column_end":15,"is_primary":true,"text":[{"text":"    let mut x = 5;","highlight_start":14,"highlight_end":15}],"label":null,"suggested_replacement":"","suggestion_applicability":"MachineApplicable","expansion":null}],"children":[],"rendered":null}],"rendered":"warning: variable does not need to be mutable\n"}"#;
+        let (diagnostics, _) = parse(unused_mut, Path::new(""));
+        let rendered = strip_ansi_codes(&render(&diagnostics)).to_string();
+        assert!(
+            rendered.contains("= help: remove this `mut`: remove `mut`\n"),
+            "{}",
+            rendered
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let output = [MOVED, UNUSED, ABORTING].join("\n");
+        let (diagnostics, _) = parse(&output, Path::new(""));
+        assert_eq!(
+            strip_ansi_codes(&render(&diagnostics)),
+            "error[E0382]: borrow of moved value: `vec0`
+  --> exercises/move_semantics/move_semantics1.rs:13:5
+   |
+13 |     vec0.push(88);
+   |     ^^^^ value borrowed here after move
+   = note: value moved here (line 10)
+   = help: consider cloning the value if the performance cost is acceptable: `.clone()`
+
+(1 warning not shown until the errors are fixed)
+"
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::config;
+use console::style;
+
+// A single line of a line-by-line comparison of two texts
+#[derive(Debug, PartialEq)]
+pub enum Change<'a> {
+    // The line is the same in both texts
+    Equal(&'a str),
+    // The line only exists in the old text
+    Delete(&'a str),
+    // The line only exists in the new text
+    Insert(&'a str),
+}
+
+// Compare two texts line by line, using their longest common subsequence.
+// The texts compared by rustlings are small, so the quadratic table is fine.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<Change<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            changes.push(Change::Equal(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            changes.push(Change::Delete(old[i]));
+            i += 1;
+        } else {
+            changes.push(Change::Insert(new[j]));
+            j += 1;
+        }
+    }
+    changes.extend(old[i..].iter().map(|line| Change::Delete(line)));
+    changes.extend(new[j..].iter().map(|line| Change::Insert(line)));
+    changes
+}
+
+// Print the changes from `old` to `new`, removed lines in red and added lines in green
+pub fn print_colored(old: &str, new: &str) {
+    for change in diff_lines(old, new) {
+        match change {
+            Change::Equal(line) => println!("  {}", line),
+            Change::Delete(line) => println!("{}", style(format!("- {}", line)).red()),
+            Change::Insert(line) => println!("{}", style(format!("+ {}", line)).green()),
+        }
+    }
+}
+
+// Render the changes from `old` to `new` as the hunks of a unified diff,
+// each with `context` unchanged lines around its changes
+pub fn unified(old: &str, new: &str, context: usize) -> Vec<String> {
+    let changes = diff_lines(old, new);
+    let changed: Vec<usize> = changes
+        .iter()
+        .enumerate()
+        .filter(|(_, change)| !matches!(change, Change::Equal(_)))
+        .map(|(i, _)| i)
+        .collect();
+
+    // Group the changes that are close enough to share their context
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &i in &changed {
+        let start = i.saturating_sub(context);
+        let end = (i + context + 1).min(changes.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut lines = Vec::new();
+    for (start, end) in hunks {
+        let old_start = changes[..start]
+            .iter()
+            .filter(|c| !matches!(c, Change::Insert(_)))
+            .count();
+        let new_start = changes[..start]
+            .iter()
+            .filter(|c| !matches!(c, Change::Delete(_)))
+            .count();
+        let hunk = &changes[start..end];
+        let old_len = hunk
+            .iter()
+            .filter(|c| !matches!(c, Change::Insert(_)))
+            .count();
+        let new_len = hunk
+            .iter()
+            .filter(|c| !matches!(c, Change::Delete(_)))
+            .count();
+        lines.push(format!(
+            "@@ -{},{} +{},{} @@",
+            old_start + 1,
+            old_len,
+            new_start + 1,
+            new_len
+        ));
+        lines.extend(hunk.iter().map(|change| match change {
+            Change::Equal(line) => format!(" {}", line),
+            Change::Delete(line) => format!("-{}", line),
+            Change::Insert(line) => format!("+{}", line),
+        }));
+    }
+    lines
+}
+
+// Print a unified diff from the file `old_name` to the file `new_name`.
+// Returns false if there were no differences to print.
+pub fn print_unified(old_name: &str, new_name: &str, old: &str, new: &str) -> bool {
+    let hunks = unified(old, new, 3);
+    if hunks.is_empty() {
+        return false;
+    }
+    println!("{}", style(format!("--- {}", old_name)).bold());
+    println!("{}", style(format!("+++ {}", new_name)).bold());
+    for line in hunks {
+        match line.chars().next() {
+            Some('@') => println!("{}", config::get().theme.accent(style(line))),
+            Some('-') => println!("{}", style(line).red()),
+            Some('+') => println!("{}", style(line).green()),
+            _ => println!("{}", line),
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let changes = diff_lines("a\nb\nc\n", "a\nc\nd\n");
+        assert_eq!(
+            changes,
+            vec![
+                Change::Equal("a"),
+                Change::Delete("b"),
+                Change::Equal("c"),
+                Change::Insert("d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unified() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n";
+        let new = "1\n2\nthree\n4\n5\n6\n7\n8\nnine\n";
+        assert_eq!(
+            unified(old, new, 1),
+            vec![
+                "@@ -2,3 +2,3 @@",
+                " 2",
+                "-3",
+                "+three",
+                " 4",
+                "@@ -8,1 +8,2 @@",
+                " 8",
+                "+nine",
+            ]
+        );
+        assert!(unified(old, old, 3).is_empty());
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let changes = diff_lines("a\nb", "a\nb");
+        assert_eq!(changes, vec![Change::Equal("a"), Change::Equal("b")]);
+    }
+}
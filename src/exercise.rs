@@ -1,30 +1,44 @@
+use crate::cache;
+use crate::config;
+use crate::diagnostics::{self, Diagnostic};
+use crate::pack::{Pack, STOCK_PACK};
+use crate::workspace::Workspace;
 use regex::Regex;
-use serde::Deserialize;
-use std::env;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
 use std::fmt::{self, Display, Formatter};
-use std::fs::{self, remove_file, File};
+use std::fs::{self, File};
 use std::io::Read;
-use std::path::PathBuf;
-use std::process::{self, Command};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
-const RUSTC_COLOR_ARGS: &[&str] = &["--color", "always"];
+// rustc's diagnostics are rendered by rustlings itself, from their JSON form
+const RUSTC_JSON_ARGS: &[&str] = &["--error-format=json"];
 const I_AM_DONE_REGEX: &str = r"(?m)^\s*///?\s*I\s+AM\s+NOT\s+DONE";
 const CONTEXT: usize = 2;
-const CLIPPY_CARGO_TOML_PATH: &str = "./exercises/clippy/Cargo.toml";
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+// The directory, next to info.toml, the crates of cargo exercises are vendored in
+const VENDOR_DIR: &str = "vendor";
+// Where cargo exercises are built, each in a directory of its own that is
+// kept between compilations so that its dependencies are only built once
+const CARGO_TARGET_DIR: &str = "target/rustlings-cargo";
 
-// Get a temporary file name that is hopefully unique
-#[inline]
-fn temp_file() -> String {
-    let thread_id: String = format!("{:?}", std::thread::current().id())
-        .chars()
-        .filter(|c| c.is_alphanumeric())
-        .collect();
-
-    format!("./temp_{}_{}", process::id(), thread_id)
+// Read everything from the pipe in the background, so that a process writing
+// lots of output doesn't block while we are waiting for it to exit
+fn read_all(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = pipe.read_to_end(&mut buffer);
+        buffer
+    })
 }
 
 // The mode of the exercise.
-#[derive(Deserialize, Copy, Clone, Debug)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum Mode {
     // Indicates that the exercise should be compiled as a binary
@@ -33,30 +47,280 @@ pub enum Mode {
     Test,
     // Indicates that the exercise should be linted with clippy
     Clippy,
-}
-
-#[derive(Deserialize)]
-pub struct ExerciseList {
-    pub exercises: Vec<Exercise>,
+    // Indicates that the exercise is a crate, whose tests should be run with cargo
+    Cargo,
 }
 
 // A representation of a rustlings exercise.
 // This is deserialized from the accompanying info.toml file
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Clone, Debug)]
 pub struct Exercise {
     // Name of the exercise
     pub name: String,
     // The path to the file containing the exercise's source code
     pub path: PathBuf,
+    // The path to the reference solution of the exercise, if there is one
+    #[serde(default)]
+    pub solution: Option<PathBuf>,
     // The mode of the exercise (Test, Compile, or Clippy)
     pub mode: Mode,
+    // The topic the exercise belongs to, by default the directory it is in
+    #[serde(default)]
+    pub topic: Option<String>,
     // The hint text associated with the exercise
+    #[serde(default)]
     pub hint: String,
+    // Hints that are revealed one at a time, used instead of `hint` if given
+    #[serde(default)]
+    pub hints: Vec<String>,
+    // The limits the exercise's binary is run with
+    #[serde(flatten)]
+    pub limits: Limits,
+    // The lints a clippy exercise is checked with
+    #[serde(default)]
+    pub lints: Lints,
+    // What a compile mode exercise has to print to pass, if anything
+    #[serde(default)]
+    pub expected_output: Option<ExpectedOutput>,
+    // The pack the exercise was loaded from, `None` for the stock exercises
+    #[serde(skip)]
+    pub pack: Option<Pack>,
+}
+
+// How exercises are compiled and run, beyond what info.toml says about them
+#[derive(Clone, Default)]
+pub struct CompileOptions {
+    // Once raised, the compilation or binary of the exercise in progress is
+    // stopped, so that `watch` can give up on a verification that is outdated
+    pub cancel: Option<Arc<AtomicBool>>,
+    // The directory compilations are cached in, `None` to always compile
+    // from scratch
+    pub cache: Option<PathBuf>,
+}
+
+impl CompileOptions {
+    fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+
+    // Run a compiler command to completion like `Command::output`,
+    // unless the compilation is cancelled in the meantime
+    fn output(&self, command: &mut Command) -> Option<Output> {
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("Failed to run 'compile' command.");
+        let stdout = read_all(child.stdout.take().unwrap());
+        let stderr = read_all(child.stderr.take().unwrap());
+        let status = loop {
+            if let Some(status) = child.try_wait().expect("Failed to run 'compile' command.") {
+                break status;
+            }
+            if self.is_cancelled() {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        Some(Output {
+            status,
+            stdout: stdout.join().unwrap_or_default(),
+            stderr: stderr.join().unwrap_or_default(),
+        })
+    }
+}
+
+// The output a compile mode exercise is expected to print.
+// In info.toml a plain string is compared trimmed, a table picks the kind of
+// comparison, e.g. `expected_output = { regex = '^(waiting\.\.\. \n){5,7}$' }`
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "ExpectedOutputDef")]
+pub enum ExpectedOutput {
+    // The output has to be exactly this text
+    Exact(String),
+    // The output has to be this text, ignoring leading and trailing blank
+    // lines and trailing whitespace on every line
+    Trimmed(String),
+    // The output has to match this regular expression
+    Regex(String),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ExpectedOutputDef {
+    Trimmed(String),
+    Table(ExpectedOutputTable),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ExpectedOutputTable {
+    Exact(String),
+    Trimmed(String),
+    Regex(String),
+}
+
+impl From<ExpectedOutputDef> for ExpectedOutput {
+    fn from(def: ExpectedOutputDef) -> Self {
+        match def {
+            ExpectedOutputDef::Trimmed(text)
+            | ExpectedOutputDef::Table(ExpectedOutputTable::Trimmed(text)) => {
+                ExpectedOutput::Trimmed(text)
+            }
+            ExpectedOutputDef::Table(ExpectedOutputTable::Exact(text)) => {
+                ExpectedOutput::Exact(text)
+            }
+            ExpectedOutputDef::Table(ExpectedOutputTable::Regex(re)) => ExpectedOutput::Regex(re),
+        }
+    }
+}
+
+impl ExpectedOutput {
+    pub fn matches(&self, output: &str) -> bool {
+        match self {
+            ExpectedOutput::Exact(expected) => output == expected,
+            ExpectedOutput::Trimmed(expected) => trim_output(output) == trim_output(expected),
+            ExpectedOutput::Regex(re) => Regex::new(re)
+                .map(|re| re.is_match(output))
+                .unwrap_or(false),
+        }
+    }
+}
+
+fn trim_output(output: &str) -> String {
+    output
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
+
+// Limits for running an exercise's binary, so that an infinite loop or
+// runaway recursion can't hang rustlings. Each of them can be set per
+// exercise in info.toml and overridden for all exercises on the command line.
+// The timeout of the exercises that don't set one can also be preferred in
+// the config.
+#[derive(Deserialize, Default, Clone, Debug, PartialEq)]
+pub struct Limits {
+    // Seconds after which the binary is killed, 0 disables the timeout
+    #[serde(default)]
+    pub timeout: Option<u64>,
+    // Maximum size of the binary's address space in MiB (Linux only)
+    #[serde(default)]
+    pub memory_limit: Option<u64>,
+    // Maximum CPU time of the binary in seconds (Linux only)
+    #[serde(default)]
+    pub cpu_limit: Option<u64>,
+}
+
+impl Limits {
+    // Replace every limit that is set in `other`
+    pub fn override_with(&mut self, other: &Limits) {
+        self.timeout = other.timeout.or(self.timeout);
+        self.memory_limit = other.memory_limit.or(self.memory_limit);
+        self.cpu_limit = other.cpu_limit.or(self.cpu_limit);
+    }
+
+    // Run with `timeout` instead of DEFAULT_TIMEOUT_SECS if the exercise
+    // doesn't set a timeout of its own
+    pub fn default_timeout(&mut self, timeout: Option<u64>) {
+        self.timeout = self.timeout.or(timeout);
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        match self.timeout.unwrap_or(DEFAULT_TIMEOUT_SECS) {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    // The memory limit in bytes. One too large to count in bytes is as good
+    // as no limit.
+    #[cfg(target_os = "linux")]
+    fn memory_limit_bytes(&self) -> Option<u64> {
+        self.memory_limit.map(|mib| mib.saturating_mul(1024 * 1024))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn apply(&self, command: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        let memory_limit = self.memory_limit_bytes();
+        let cpu_limit = self.cpu_limit;
+        if memory_limit.is_none() && cpu_limit.is_none() {
+            return;
+        }
+        let set_limit = |resource, limit: u64| {
+            let rlimit = libc::rlimit {
+                rlim_cur: limit as libc::rlim_t,
+                rlim_max: limit as libc::rlim_t,
+            };
+            if unsafe { libc::setrlimit(resource, &rlimit) } == 0 {
+                Ok(())
+            } else {
+                Err(std::io::Error::last_os_error())
+            }
+        };
+        // Only async-signal-safe calls are made between fork and exec
+        unsafe {
+            command.pre_exec(move || {
+                if let Some(limit) = memory_limit {
+                    set_limit(libc::RLIMIT_AS, limit)?;
+                }
+                if let Some(limit) = cpu_limit {
+                    set_limit(libc::RLIMIT_CPU, limit)?;
+                }
+                Ok(())
+            });
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn apply(&self, _command: &mut Command) {}
+}
+
+// The lints a clippy exercise is checked with, on top of denying every
+// warning, e.g. `lints = { allow = ["clippy::needless_return"] }`.
+// Lints that are denied later win over the ones allowed before them.
+#[derive(Deserialize, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Lints {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+    // A clippy.toml to configure the lints with, e.g. their thresholds
+    #[serde(default)]
+    pub config: Option<PathBuf>,
+}
+
+impl Lints {
+    // The arguments clippy is run with
+    fn args(&self) -> Vec<String> {
+        let mut args = vec![String::from("-D"), String::from("warnings")];
+        for lint in &self.allow {
+            args.push(String::from("-A"));
+            args.push(lint.clone());
+        }
+        for lint in &self.deny {
+            args.push(String::from("-D"));
+            args.push(lint.clone());
+        }
+        args
+    }
 }
 
 // An enum to track of the state of an Exercise.
 // An Exercise can be either Done or Pending
-#[derive(PartialEq, Debug)]
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
 pub enum State {
     // The state of the exercise once it's been completed
     Done,
@@ -65,7 +329,7 @@ pub enum State {
 }
 
 // The context information of a pending exercise
-#[derive(PartialEq, Debug)]
+#[derive(Serialize, PartialEq, Debug)]
 pub struct ContextLine {
     // The source code that is still pending completion
     pub line: String,
@@ -78,45 +342,185 @@ pub struct ContextLine {
 // The result of compiling an exercise
 pub struct CompiledExercise<'a> {
     exercise: &'a Exercise,
-    _handle: FileHandle,
+    options: &'a CompileOptions,
+    // Removes the build artifacts once the compilation goes away, `None`
+    // for binaries from the cache
+    _workspace: Option<Workspace>,
+    // The binaries to run, more than one for a cargo exercise with several test targets
+    binaries: Vec<String>,
 }
 
 impl<'a> CompiledExercise<'a> {
-    // Run the compiled exercise
+    // Run the compiled exercise, one binary after the other
     pub fn run(&self) -> Result<ExerciseOutput, ExerciseOutput> {
-        self.exercise.run()
+        let mut output = ExerciseOutput::default();
+        for binary in &self.binaries {
+            let (result, failed) = match self.exercise.run(binary, self.options) {
+                Ok(result) => (result, false),
+                Err(result) => (result, true),
+            };
+            output.stdout.push_str(&result.stdout);
+            output.stderr.push_str(&result.stderr);
+            output.timed_out |= result.timed_out;
+            output.unexpected_output |= result.unexpected_output;
+            output.cancelled |= result.cancelled;
+            if failed {
+                return Err(output);
+            }
+        }
+        Ok(output)
     }
 }
 
 // A representation of an already executed binary
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ExerciseOutput {
     // The textual contents of the standard output of the binary
     pub stdout: String,
     // The textual contents of the standard error of the binary
     pub stderr: String,
+    // Whether the binary was killed for running longer than its timeout
+    pub timed_out: bool,
+    // Whether the binary succeeded, but didn't print the expected output
+    pub unexpected_output: bool,
+    // Whether the compilation or the binary was stopped by cancelling the exercise
+    pub cancelled: bool,
+    // What the compiler had to say about the exercise when compiling it failed
+    pub diagnostics: Vec<Diagnostic>,
 }
 
-struct FileHandle;
+impl ExerciseOutput {
+    // The lints that failed the compilation of the exercise, e.g. `clippy::approx_constant`
+    pub fn lints(&self) -> Vec<String> {
+        diagnostics::lints(&self.diagnostics)
+    }
 
-impl Drop for FileHandle {
-    fn drop(&mut self) {
-        clean();
+    // The error codes of the compiler's errors, e.g. `E0382`
+    pub fn codes(&self) -> Vec<String> {
+        diagnostics::error_codes(&self.diagnostics)
+    }
+
+    fn cancelled() -> Self {
+        ExerciseOutput {
+            cancelled: true,
+            ..ExerciseOutput::default()
+        }
     }
 }
 
 impl Exercise {
-    pub fn compile(&self) -> Result<CompiledExercise, ExerciseOutput> {
+    // The hints of the exercise in the order they are revealed in.
+    // An exercise with a single `hint` has exactly one level.
+    pub fn hint_levels(&self) -> Vec<&str> {
+        if !self.hints.is_empty() {
+            self.hints.iter().map(String::as_str).collect()
+        } else if !self.hint.trim().is_empty() {
+            vec![&self.hint]
+        } else {
+            Vec::new()
+        }
+    }
+
+    // The name of the pack the exercise belongs to
+    pub fn pack_name(&self) -> &str {
+        self.pack.as_ref().map_or(STOCK_PACK, |p| &p.name)
+    }
+
+    // The README.md introducing the exercise's topic, next to the exercise
+    pub fn readme(&self) -> Option<PathBuf> {
+        let readme = self.path.parent()?.join("README.md");
+        if readme.is_file() {
+            Some(readme)
+        } else {
+            None
+        }
+    }
+
+    // Compile the exercise, or take the result of an earlier compilation of
+    // the same sources from the cache
+    pub fn compile<'a>(
+        &'a self,
+        options: &'a CompileOptions,
+    ) -> Result<CompiledExercise<'a>, ExerciseOutput> {
+        let cache_dir = match &options.cache {
+            Some(cache_dir) => cache_dir,
+            None => return self.build(options),
+        };
+        let mut flags = config::get().color.compiler_args();
+        flags.extend(config::get().rustc_flags.iter().cloned());
+        if self.mode == Mode::Clippy {
+            flags.extend(self.lints.args());
+            if let Some(config) = &self.lints.config {
+                flags.push(fs::read_to_string(config).unwrap_or_default());
+            }
+        }
+        let sources = self.sources();
+        let key = cache::key(self, &sources, &flags);
+        if let Some(result) = cache::load(cache_dir, self, &key) {
+            return result.map(|binaries| CompiledExercise {
+                exercise: self,
+                options,
+                _workspace: None,
+                binaries,
+            });
+        }
+        let result = self.build(options);
+        let stored = match &result {
+            Ok(compiled) => Ok(compiled.binaries.clone()),
+            Err(output) if output.cancelled => return result,
+            Err(output) => Err(ExerciseOutput {
+                stdout: output.stdout.clone(),
+                stderr: output.stderr.clone(),
+                diagnostics: output.diagnostics.clone(),
+                ..ExerciseOutput::default()
+            }),
+        };
+        // cargo writes the Cargo.lock of a crate that has none yet, so the
+        // key is taken again for the next compilation to find the entry
+        let key = cache::key(self, &sources, &flags);
+        // The cache only saves time, the exercise was compiled either way
+        let _ignored = cache::store(cache_dir, self, &key, &stored);
+        result
+    }
+
+    fn build<'a>(
+        &'a self,
+        options: &'a CompileOptions,
+    ) -> Result<CompiledExercise<'a>, ExerciseOutput> {
+        if self.mode == Mode::Cargo {
+            return self.compile_crate(options);
+        }
+        let workspace =
+            Workspace::new().expect("Failed to create a directory to compile the exercise in");
+        let binary = workspace.path("exercise");
         let cmd = match self.mode {
-            Mode::Compile => Command::new("rustc")
-                .args(&[self.path.to_str().unwrap(), "-o", &temp_file()])
-                .args(RUSTC_COLOR_ARGS)
-                .output(),
-            Mode::Test => Command::new("rustc")
-                .args(&["--test", self.path.to_str().unwrap(), "-o", &temp_file()])
-                .args(RUSTC_COLOR_ARGS)
-                .output(),
+            Mode::Compile => options.output(
+                Command::new("rustc")
+                    .arg(&self.path)
+                    .arg("-o")
+                    .arg(&binary)
+                    .args(RUSTC_JSON_ARGS)
+                    .args(&config::get().rustc_flags),
+            ),
+            Mode::Test => options.output(
+                Command::new("rustc")
+                    .arg("--test")
+                    .arg(&self.path)
+                    .arg("-o")
+                    .arg(&binary)
+                    .args(RUSTC_JSON_ARGS)
+                    .args(&config::get().rustc_flags),
+            ),
             Mode::Clippy => {
+                // Every compilation gets a manifest of its own, with its own
+                // target directory, so that concurrent clippy runs don't race
+                // and no stale lint results from earlier runs are picked up.
+                let clippy_dir = workspace.path("clippy");
+                let manifest_path = clippy_dir.join("Cargo.toml");
+                let source_path = fs::canonicalize(&self.path)
+                    .expect("We were unable to find the exercise file!");
+                // The names of pack exercises contain a `/`, which cargo doesn't allow
+                let package = self.name.replace('/', "_");
                 let cargo_toml = format!(
                     r#"[package]
 name = "{}"
@@ -124,95 +528,225 @@ version = "0.0.1"
 edition = "2018"
 [[bin]]
 name = "{}"
-path = "{}.rs""#,
-                    self.name, self.name, self.name
+path = {:?}
+[workspace]"#,
+                    package, package, source_path
                 );
-                let cargo_toml_error_msg = if env::var("NO_EMOJI").is_ok() {
+                let cargo_toml_error_msg = if !config::get().emoji {
                     "Failed to write Clippy Cargo.toml file."
                 } else {
                     "Failed to write 📎 Clippy 📎 Cargo.toml file."
                 };
-                fs::write(CLIPPY_CARGO_TOML_PATH, cargo_toml).expect(cargo_toml_error_msg);
+                fs::create_dir_all(&clippy_dir).expect(cargo_toml_error_msg);
+                fs::write(&manifest_path, cargo_toml).expect(cargo_toml_error_msg);
+                if let Some(config) = &self.lints.config {
+                    fs::copy(config, clippy_dir.join("clippy.toml"))
+                        .expect("Failed to copy the clippy.toml of the exercise.");
+                }
                 // To support the ability to run the clipy exercises, build
                 // an executable, in addition to running clippy. With a
                 // compilation failure, this would silently fail. But we expect
                 // clippy to reflect the same failure while compiling later.
-                Command::new("rustc")
-                    .args(&[self.path.to_str().unwrap(), "-o", &temp_file()])
-                    .args(RUSTC_COLOR_ARGS)
-                    .output()
-                    .expect("Failed to compile!");
-                // Due to an issue with Clippy, a cargo clean is required to catch all lints.
-                // See https://github.com/rust-lang/rust-clippy/issues/2604
-                // This is already fixed on Clippy's master branch. See this issue to track merging into Cargo:
-                // https://github.com/rust-lang/rust-clippy/issues/3837
-                Command::new("cargo")
-                    .args(&["clean", "--manifest-path", CLIPPY_CARGO_TOML_PATH])
-                    .args(RUSTC_COLOR_ARGS)
-                    .output()
-                    .expect("Failed to run 'cargo clean'");
-                Command::new("cargo")
-                    .args(&["clippy", "--manifest-path", CLIPPY_CARGO_TOML_PATH])
-                    .args(RUSTC_COLOR_ARGS)
-                    .args(&["--", "-D", "warnings"])
-                    .output()
+                options
+                    .output(
+                        Command::new("rustc")
+                            .arg(&self.path)
+                            .arg("-o")
+                            .arg(&binary)
+                            .args(RUSTC_JSON_ARGS)
+                            .args(&config::get().rustc_flags),
+                    )
+                    .and_then(|_| {
+                        // Only the configuration of the exercise is used, not
+                        // one the learner happens to have elsewhere
+                        options.output(
+                            Command::new("cargo")
+                                .args(["clippy", "--manifest-path"])
+                                .arg(&manifest_path)
+                                .args(["--message-format", "json"])
+                                .args(config::get().color.compiler_args())
+                                .env("CLIPPY_CONF_DIR", &clippy_dir)
+                                .arg("--")
+                                .args(self.lints.args())
+                                .args(&config::get().rustc_flags),
+                        )
+                    })
             }
-        }
-        .expect("Failed to run 'compile' command.");
+            Mode::Cargo => unreachable!("cargo exercises are compiled by compile_crate"),
+        };
+        let cmd = match cmd {
+            Some(cmd) => cmd,
+            None => return Err(ExerciseOutput::cancelled()),
+        };
 
         if cmd.status.success() {
             Ok(CompiledExercise {
                 exercise: self,
-                _handle: FileHandle,
+                options,
+                _workspace: Some(workspace),
+                binaries: vec![binary.display().to_string()],
             })
+        } else if self.mode == Mode::Clippy {
+            Err(compile_error(&cmd.stdout, Path::new(""), &cmd.stderr))
         } else {
-            clean();
-            Err(ExerciseOutput {
-                stdout: String::from_utf8_lossy(&cmd.stdout).to_string(),
-                stderr: String::from_utf8_lossy(&cmd.stderr).to_string(),
+            Err(compile_error(&cmd.stderr, Path::new(""), &[]))
+        }
+    }
+
+    // The directory the vendored dependencies of cargo exercises are in
+    pub fn vendor_dir(&self) -> PathBuf {
+        match &self.pack {
+            Some(pack) => pack.dir.join(VENDOR_DIR),
+            None => PathBuf::from(VENDOR_DIR),
+        }
+    }
+
+    // Build the tests of a cargo exercise without running them. Dependencies
+    // are only taken from the vendor directory or cargo's local cache, so
+    // that exercises work without network access.
+    fn compile_crate<'a>(
+        &'a self,
+        options: &'a CompileOptions,
+    ) -> Result<CompiledExercise<'a>, ExerciseOutput> {
+        let manifest_path = self.path.join("Cargo.toml");
+        // The names of pack exercises contain a `/`
+        let target_dir = Path::new(CARGO_TARGET_DIR).join(self.name.replace('/', "_"));
+        let mut command = Command::new("cargo");
+        if let Ok(vendor_dir) = fs::canonicalize(self.vendor_dir()) {
+            command
+                .args([
+                    "--config",
+                    "source.crates-io.replace-with='vendored-sources'",
+                ])
+                .arg("--config")
+                .arg(format!(
+                    "source.vendored-sources.directory={:?}",
+                    vendor_dir
+                ));
+        }
+        let cmd = match options.output(
+            command
+                .args(["test", "--no-run", "--offline"])
+                .args(["--message-format", "json"])
+                .arg("--manifest-path")
+                .arg(&manifest_path)
+                .arg("--target-dir")
+                .arg(&target_dir)
+                .args(config::get().color.compiler_args()),
+        ) {
+            Some(cmd) => cmd,
+            None => return Err(ExerciseOutput::cancelled()),
+        };
+
+        if !cmd.status.success() {
+            // The file names in the diagnostics are relative to the crate
+            return Err(compile_error(&cmd.stdout, &self.path, &cmd.stderr));
+        }
+        let binaries = String::from_utf8_lossy(&cmd.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .filter(|message| {
+                message["reason"] == "compiler-artifact" && message["profile"]["test"] == true
             })
+            .filter_map(|message| message["executable"].as_str().map(String::from))
+            .collect::<Vec<_>>();
+        // Passing without a single test would let any crate that compiles pass
+        if binaries.is_empty() {
+            return Err(ExerciseOutput {
+                stderr: format!(
+                    "{} has no tests, so there is nothing to check it with.",
+                    self.path.display()
+                ),
+                ..ExerciseOutput::default()
+            });
         }
+        Ok(CompiledExercise {
+            exercise: self,
+            options,
+            _workspace: None,
+            binaries,
+        })
     }
 
-    fn run(&self) -> Result<ExerciseOutput, ExerciseOutput> {
+    fn run(
+        &self,
+        binary: &str,
+        options: &CompileOptions,
+    ) -> Result<ExerciseOutput, ExerciseOutput> {
         let arg = match self.mode {
-            Mode::Test => "--show-output",
+            Mode::Test | Mode::Cargo => "--show-output",
             _ => "",
         };
-        let cmd = Command::new(&temp_file())
+        let mut command = Command::new(binary);
+        if self.mode == Mode::Cargo {
+            // Like `cargo test`, so that tests can find files of the crate
+            command.current_dir(&self.path);
+        }
+        command
             .arg(arg)
-            .output()
-            .expect("Failed to run 'run' command");
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        self.limits.apply(&mut command);
+        let mut child = command.spawn().expect("Failed to run 'run' command");
+
+        let stdout = read_all(child.stdout.take().unwrap());
+        let stderr = read_all(child.stderr.take().unwrap());
+
+        let deadline = self
+            .limits
+            .timeout()
+            .map(|timeout| Instant::now() + timeout);
+        let status = loop {
+            if let Some(status) = child.try_wait().expect("Failed to wait for 'run' command") {
+                break Ok(status);
+            }
+            let cancelled = options.is_cancelled();
+            if cancelled || deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                let _ = child.kill();
+                let _ = child.wait();
+                break Err(cancelled);
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
 
-        let output = ExerciseOutput {
-            stdout: String::from_utf8_lossy(&cmd.stdout).to_string(),
-            stderr: String::from_utf8_lossy(&cmd.stderr).to_string(),
+        let mut output = ExerciseOutput {
+            stdout: String::from_utf8_lossy(&stdout.join().unwrap_or_default()).to_string(),
+            stderr: String::from_utf8_lossy(&stderr.join().unwrap_or_default()).to_string(),
+            timed_out: status == Err(false),
+            unexpected_output: false,
+            cancelled: status == Err(true),
+            ..ExerciseOutput::default()
         };
+        let status = status.ok();
 
-        if cmd.status.success() {
-            Ok(output)
-        } else {
-            Err(output)
+        match (status, &self.expected_output) {
+            (Some(status), Some(expected))
+                if status.success()
+                    && self.mode == Mode::Compile
+                    && !expected.matches(&output.stdout) =>
+            {
+                output.unexpected_output = true;
+                Err(output)
+            }
+            (Some(status), _) if status.success() => Ok(output),
+            _ => Err(output),
         }
     }
 
-    pub fn state(&self) -> State {
-        let mut source_file =
-            File::open(&self.path).expect("We were unable to open the exercise file!");
-
-        let source = {
-            let mut s = String::new();
-            source_file
-                .read_to_string(&mut s)
-                .expect("We were unable to read the exercise file!");
-            s
-        };
+    // The timeout the exercise's binary is run with, if any
+    pub fn timeout(&self) -> Option<Duration> {
+        self.limits.timeout()
+    }
 
+    pub fn state(&self) -> State {
         let re = Regex::new(I_AM_DONE_REGEX).unwrap();
 
-        if !re.is_match(&source) {
-            return State::Done;
-        }
+        // The marker of a cargo exercise may be in any of its files
+        let source = match self.sources().into_iter().find(|s| re.is_match(s)) {
+            Some(source) => source,
+            None => return State::Done,
+        };
 
         let matched_line_index = source
             .lines()
@@ -238,14 +772,86 @@ path = "{}.rs""#,
         State::Pending(context)
     }
 
-    // Check that the exercise looks to be solved using self.state()
-    // This is not the best way to check since
-    // the user can just remove the "I AM NOT DONE" string from the file
-    // without actually having solved anything.
-    // The only other way to truly check this would to compile and run
-    // the exercise; which would be both costly and counterintuitive
-    pub fn looks_done(&self) -> bool {
-        self.state() == State::Done
+    // Hash the source of the exercise, leaving out the `I AM NOT DONE` marker
+    // so that removing it doesn't invalidate an earlier verification.
+    // This uses FNV-1a, as the hash has to stay stable across Rust versions.
+    pub fn source_hash(&self) -> String {
+        let re = Regex::new(I_AM_DONE_REGEX).unwrap();
+        let sources = self.sources();
+        let hash = fnv1a(
+            sources
+                .iter()
+                .flat_map(|source| source.lines())
+                .filter(|line| !re.is_match(line))
+                .flat_map(|line| line.bytes().chain(std::iter::once(b'\n'))),
+        );
+        format!("{:016x}", hash)
+    }
+
+    // The contents of the exercise file, or of every file of a cargo exercise
+    pub fn sources(&self) -> Vec<String> {
+        if self.mode != Mode::Cargo {
+            let mut source_file =
+                File::open(&self.path).expect("We were unable to open the exercise file!");
+
+            let mut source = String::new();
+            source_file
+                .read_to_string(&mut source)
+                .expect("We were unable to read the exercise file!");
+            return vec![source];
+        }
+        let mut files = Vec::new();
+        crate_files(&self.path, &mut files);
+        files.sort();
+        files
+            .iter()
+            .map(|file| {
+                fs::read_to_string(file).expect("We were unable to read the exercise file!")
+            })
+            .collect()
+    }
+}
+
+// The output of a failed compilation, rendered from the JSON messages of the
+// compiler. What cargo printed itself only matters when there are no
+// diagnostics that tell why the compilation failed, e.g. with a broken manifest.
+fn compile_error(messages: &[u8], base: &Path, cargo_stderr: &[u8]) -> ExerciseOutput {
+    let (diagnostics, other) = diagnostics::parse(&String::from_utf8_lossy(messages), base);
+    let mut stderr = diagnostics::render(&diagnostics);
+    stderr.push_str(&other);
+    if diagnostics.is_empty() {
+        stderr.push_str(&String::from_utf8_lossy(cargo_stderr));
+    }
+    ExerciseOutput {
+        stderr,
+        diagnostics,
+        ..ExerciseOutput::default()
+    }
+}
+
+// The 64-bit FNV-1a hash of the bytes
+pub fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes
+        .into_iter()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
+// Collect the manifest and the Rust sources of a crate, leaving out build artifacts
+fn crate_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).expect("We were unable to find the exercise crate!");
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if path.file_name() != Some(OsStr::new("target")) {
+                crate_files(&path, files);
+            }
+        } else if path.extension() == Some(OsStr::new("rs"))
+            || path.file_name() == Some(OsStr::new("Cargo.toml"))
+        {
+            files.push(path);
+        }
     }
 }
 
@@ -255,11 +861,6 @@ impl Display for Exercise {
     }
 }
 
-#[inline]
-fn clean() {
-    let _ignored = remove_file(&temp_file());
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -267,16 +868,63 @@ mod test {
 
     #[test]
     fn test_clean() {
-        File::create(&temp_file()).unwrap();
         let exercise = Exercise {
             name: String::from("example"),
             path: PathBuf::from("tests/fixture/state/pending_exercise.rs"),
+            solution: None,
             mode: Mode::Compile,
+            topic: None,
             hint: String::from(""),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: None,
+            pack: None,
         };
-        let compiled = exercise.compile().unwrap();
+        let options = CompileOptions::default();
+        let compiled = exercise.compile(&options).unwrap();
+        let binary = compiled.binaries[0].clone();
+        assert!(Path::new(&binary).exists());
         drop(compiled);
-        assert!(!Path::new(&temp_file()).exists());
+        assert!(!Path::new(&binary).exists());
+    }
+
+    #[test]
+    fn test_expected_output() {
+        let exact = ExpectedOutput::Exact("a\n".into());
+        assert!(exact.matches("a\n"));
+        assert!(!exact.matches("a"));
+
+        let trimmed = ExpectedOutput::Trimmed("a\nb".into());
+        assert!(trimmed.matches("\na  \nb\n\n"));
+        assert!(!trimmed.matches("a\n\nb"));
+
+        let regex = ExpectedOutput::Regex(r"^(x\n){2,3}$".into());
+        assert!(regex.matches("x\nx\n"));
+        assert!(!regex.matches("x\n"));
+    }
+
+    #[test]
+    fn test_wrong_output() {
+        let exercise = Exercise {
+            name: "wrong_output".into(),
+            path: PathBuf::from("tests/fixture/output/wrongOutput.rs"),
+            solution: None,
+            mode: Mode::Compile,
+            topic: None,
+            hint: String::new(),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: Some(ExpectedOutput::Trimmed("Hello, world!".into())),
+            pack: None,
+        };
+        let out = exercise
+            .compile(&CompileOptions::default())
+            .unwrap()
+            .run()
+            .unwrap_err();
+        assert!(out.unexpected_output);
     }
 
     #[test]
@@ -284,8 +932,15 @@ mod test {
         let exercise = Exercise {
             name: "pending_exercise".into(),
             path: PathBuf::from("tests/fixture/state/pending_exercise.rs"),
+            solution: None,
             mode: Mode::Compile,
+            topic: None,
             hint: String::new(),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: None,
+            pack: None,
         };
 
         let state = exercise.state();
@@ -325,8 +980,15 @@ mod test {
         let exercise = Exercise {
             name: "finished_exercise".into(),
             path: PathBuf::from("tests/fixture/state/finished_exercise.rs"),
+            solution: None,
             mode: Mode::Compile,
+            topic: None,
             hint: String::new(),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: None,
+            pack: None,
         };
 
         assert_eq!(exercise.state(), State::Done);
@@ -337,10 +999,85 @@ mod test {
         let exercise = Exercise {
             name: "exercise_with_output".into(),
             path: PathBuf::from("tests/fixture/success/testSuccess.rs"),
+            solution: None,
             mode: Mode::Test,
+            topic: None,
             hint: String::new(),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: None,
+            pack: None,
         };
-        let out = exercise.compile().unwrap().run().unwrap();
+        let out = exercise
+            .compile(&CompileOptions::default())
+            .unwrap()
+            .run()
+            .unwrap();
         assert!(out.stdout.contains("THIS TEST TOO SHALL PASS"));
     }
+
+    #[test]
+    fn test_cancel() {
+        let exercise = Exercise {
+            name: "infinite_loop".into(),
+            path: PathBuf::from("tests/fixture/timeout/infiniteLoop.rs"),
+            solution: None,
+            mode: Mode::Compile,
+            topic: None,
+            hint: String::new(),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: None,
+            pack: None,
+        };
+        let options = CompileOptions {
+            cancel: Some(Arc::new(AtomicBool::new(false))),
+            cache: None,
+        };
+        let compiled = exercise.compile(&options).unwrap();
+        let cancel = Arc::clone(options.cancel.as_ref().unwrap());
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            cancel.store(true, Ordering::Relaxed);
+        });
+        let out = compiled.run().unwrap_err();
+        assert!(out.cancelled);
+        assert!(!out.timed_out);
+
+        // Nothing is compiled once the exercise is cancelled
+        let out = exercise.compile(&options).err().unwrap();
+        assert!(out.cancelled);
+    }
+
+    #[test]
+    fn test_default_timeout_keeps_exercise_timeout() {
+        let mut limits = Limits {
+            timeout: Some(1),
+            ..Limits::default()
+        };
+        limits.default_timeout(Some(30));
+        assert_eq!(limits.timeout(), Some(Duration::from_secs(1)));
+        limits.override_with(&Limits {
+            timeout: Some(0),
+            ..Limits::default()
+        });
+        assert_eq!(limits.timeout(), None);
+
+        let mut limits = Limits::default();
+        limits.default_timeout(Some(30));
+        assert_eq!(limits.timeout(), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_memory_limit_does_not_overflow() {
+        let limits = |memory_limit| Limits {
+            memory_limit: Some(memory_limit),
+            ..Limits::default()
+        };
+        assert_eq!(limits(64).memory_limit_bytes(), Some(64 * 1024 * 1024));
+        assert_eq!(limits(u64::MAX).memory_limit_bytes(), Some(u64::MAX));
+    }
 }
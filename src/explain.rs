@@ -0,0 +1,171 @@
+use crate::cache::{rustc_version, EXPLANATIONS_DIR};
+use crate::exercise::fnv1a;
+use crate::markdown;
+use console::style;
+use crossterm::terminal;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+// The canonical form of an error code or a clippy lint as the learner typed
+// it: `e382` and `0382` become `E0382`, `clippy::needless_return` stays as it
+// is. `None` if it is neither.
+pub fn normalize(code: &str) -> Option<String> {
+    let code = code.trim();
+    if let Some(lint) = code.strip_prefix("clippy::") {
+        let valid = !lint.is_empty()
+            && lint
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        return if valid { Some(code.to_string()) } else { None };
+    }
+    let number = code.strip_prefix(|c| c == 'E' || c == 'e').unwrap_or(code);
+    if (1..=4).contains(&number.len()) && number.chars().all(|c| c.is_ascii_digit()) {
+        Some(format!("E{:0>4}", number))
+    } else {
+        None
+    }
+}
+
+// The explanation of an error code of rustc, or of a clippy lint, in
+// Markdown. Explanations come with the toolchain, so once looked up they are
+// kept in the cache directory, if there is one, for the toolchain they came from.
+fn explanation(code: &str, cache: Option<&Path>) -> Result<String, String> {
+    let file = cache.map(|cache| {
+        cache.join(EXPLANATIONS_DIR).join(format!(
+            "{}-{:016x}.md",
+            code.replace("::", "-"),
+            fnv1a(rustc_version().bytes())
+        ))
+    });
+    if let Some(text) = file.as_ref().and_then(|file| fs::read_to_string(file).ok()) {
+        return Ok(text);
+    }
+    let output = match code.strip_prefix("clippy::") {
+        Some(lint) => Command::new("cargo")
+            .args(["clippy", "--explain", lint])
+            .output(),
+        None => Command::new("rustc").args(["--explain", code]).output(),
+    }
+    .map_err(|e| format!("Failed to look up `{}`: {}", code, e))?;
+    let text = String::from_utf8_lossy(&output.stdout).into_owned();
+    if !output.status.success() || text.trim().is_empty() {
+        return Err(format!("There is no explanation for `{}`.", code));
+    }
+    if let Some(file) = file {
+        // Looking it up again next time is fine
+        let _ignored = file
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&file, &text));
+    }
+    Ok(text)
+}
+
+// The explanations of the codes, one after another under a heading each,
+// rendered for the terminal. Codes without an explanation are left out,
+// unless none of them has one.
+pub fn explain(codes: &[String], cache: Option<&Path>) -> Result<Vec<String>, String> {
+    let mut lines = Vec::new();
+    let mut missing = Vec::new();
+    for code in codes {
+        let text = match explanation(code, cache) {
+            Ok(text) => text,
+            Err(e) => {
+                missing.push(e);
+                continue;
+            }
+        };
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(style(code).bold().underlined().to_string());
+        lines.push(String::new());
+        lines.extend(markdown::render(&text));
+    }
+    if lines.is_empty() && !missing.is_empty() {
+        return Err(missing.join("\n"));
+    }
+    Ok(lines)
+}
+
+// Show the lines through the learner's pager, `$PAGER` or `less`, if they
+// don't fit on the screen. They are printed as they are when there is no
+// terminal or pager.
+pub fn page(lines: &[String]) {
+    let fits = terminal::size().map_or(true, |(_, rows)| lines.len() < usize::from(rows));
+    if fits || !io::stdout().is_terminal() || show_in_pager(lines).is_err() {
+        for line in lines {
+            println!("{}", line);
+        }
+    }
+}
+
+fn show_in_pager(lines: &[String]) -> io::Result<()> {
+    let pager = env::var("PAGER").unwrap_or_else(|_| String::from("less"));
+    let mut words = pager.split_whitespace();
+    let program = words
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "PAGER is empty"))?;
+    let mut command = Command::new(program);
+    command.args(words).stdin(Stdio::piped());
+    // Like git, let less show colors and quit at the end
+    if env::var_os("LESS").is_none() {
+        command.env("LESS", "FRX");
+    }
+    let mut child = command.spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        for line in lines {
+            // The pager was quit before reaching the end
+            if writeln!(stdin, "{}", line).is_err() {
+                break;
+            }
+        }
+    }
+    child.wait()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("E0382").as_deref(), Some("E0382"));
+        assert_eq!(normalize("e382").as_deref(), Some("E0382"));
+        assert_eq!(normalize(" 0308 ").as_deref(), Some("E0308"));
+        assert_eq!(
+            normalize("clippy::needless_return").as_deref(),
+            Some("clippy::needless_return")
+        );
+        assert_eq!(normalize("E03820"), None);
+        assert_eq!(normalize("borrow"), None);
+        assert_eq!(normalize("clippy::"), None);
+    }
+
+    #[test]
+    fn test_explanation_is_cached() {
+        let dir = Path::new("target/test-explanations");
+        let _ = fs::remove_dir_all(dir);
+        let text = explanation("E0382", Some(dir)).unwrap();
+        assert!(text.contains("moved"));
+        assert_eq!(fs::read_dir(dir.join(EXPLANATIONS_DIR)).unwrap().count(), 1);
+        assert_eq!(explanation("E0382", Some(dir)).unwrap(), text);
+        assert!(explanation("E9999", Some(dir)).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_explain_skips_unknown_codes() {
+        let codes = [String::from("E9999"), String::from("E0382")];
+        let lines = explain(&codes, None).unwrap();
+        assert!(lines[0].contains("E0382"));
+        assert_eq!(
+            explain(&codes[..1], None),
+            Err(String::from("There is no explanation for `E9999`."))
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::diagnostics::{Applicability, Diagnostic};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::iter;
+use std::path::{Path, PathBuf};
+
+// A piece of a file the compiler suggests to replace, by its byte offsets
+#[derive(Clone, Debug, PartialEq)]
+struct Replacement {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+impl Replacement {
+    // Two insertions at the same place overlap too, as their order is unclear
+    fn overlaps(&self, other: &Replacement) -> bool {
+        (self.start < other.end && other.start < self.end) || self.start == other.start
+    }
+}
+
+// The fixes for one of the exercise's files
+#[derive(Debug)]
+pub struct FileFix {
+    // The path as the compiler gave it, relative to the current directory
+    pub path: PathBuf,
+    pub old: String,
+    pub new: String,
+}
+
+// The suggestions of the compiler that it is sure about, each of them with
+// the replacements it is made of, which are only applied together
+fn suggestions(diagnostics: &[Diagnostic]) -> Vec<Vec<(&str, Replacement)>> {
+    let mut suggestions = Vec::new();
+    for diagnostic in diagnostics {
+        for suggestion in iter::once(diagnostic).chain(&diagnostic.children) {
+            let replacements: Vec<(&str, Replacement)> = suggestion
+                .spans
+                .iter()
+                .filter(|span| {
+                    span.suggestion_applicability == Some(Applicability::MachineApplicable)
+                })
+                .filter_map(|span| {
+                    span.suggested_replacement.as_ref().map(|text| {
+                        let replacement = Replacement {
+                            start: span.byte_start,
+                            end: span.byte_end,
+                            text: text.clone(),
+                        };
+                        (span.file_name.as_str(), replacement)
+                    })
+                })
+                .collect();
+            if !replacements.is_empty() {
+                suggestions.push(replacements);
+            }
+        }
+    }
+    suggestions
+}
+
+// The fixes the diagnostics suggest for the files of the exercise at `path`,
+// the exercise's file or, for cargo exercises, the files in its crate.
+// Suggestions for other files are left out, as are ones that overlap with
+// one before them.
+pub fn plan(path: &Path, diagnostics: &[Diagnostic]) -> io::Result<Vec<FileFix>> {
+    let root = fs::canonicalize(path)?;
+    let mut files: BTreeMap<PathBuf, Vec<Replacement>> = BTreeMap::new();
+    for suggestion in suggestions(diagnostics) {
+        let in_exercise = suggestion
+            .iter()
+            .all(|(file, _)| fs::canonicalize(file).is_ok_and(|file| file.starts_with(&root)));
+        let overlaps = suggestion.iter().any(|(file, replacement)| {
+            files
+                .get(Path::new(file))
+                .is_some_and(|accepted| accepted.iter().any(|r| r.overlaps(replacement)))
+        });
+        if in_exercise && !overlaps {
+            for (file, replacement) in suggestion {
+                files
+                    .entry(PathBuf::from(file))
+                    .or_default()
+                    .push(replacement);
+            }
+        }
+    }
+    files
+        .into_iter()
+        .map(|(path, replacements)| {
+            let old = fs::read_to_string(&path)?;
+            let new = apply(&old, replacements).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} changed since it was compiled", path.display()),
+                )
+            })?;
+            Ok(FileFix { path, old, new })
+        })
+        .collect()
+}
+
+// The source with the replacements made, `None` if they don't fit it
+fn apply(source: &str, mut replacements: Vec<Replacement>) -> Option<String> {
+    let mut new = source.to_string();
+    // From the back, so that the offsets of the ones before stay right
+    replacements.sort_by_key(|r| r.start);
+    for replacement in replacements.iter().rev() {
+        let fits = replacement.start <= replacement.end
+            && replacement.end <= new.len()
+            && new.is_char_boundary(replacement.start)
+            && new.is_char_boundary(replacement.end);
+        if !fits {
+            return None;
+        }
+        new.replace_range(replacement.start..replacement.end, &replacement.text);
+    }
+    Some(new)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::diagnostics;
+
+    fn span(start: usize, end: usize, replacement: &str, applicability: &str) -> String {
+        format!(
+            r#"{{"file_name":"tests/fixture/lints/needlessReturn.rs","byte_start":{},"byte_end":{},"line_start":2,"line_end":2,"column_start":5,"column_end":15,"is_primary":true,"label":null,"suggested_replacement":"{}","suggestion_applicability":"{}"}}"#,
+            start, end, replacement, applicability
+        )
+    }
+
+    #[test]
+    fn test_plan() {
+        // `return 42;` is at 25..35
+        let source = fs::read_to_string("tests/fixture/lints/needlessReturn.rs").unwrap();
+        assert_eq!(&source[25..35], "return 42;");
+        let messages = format!(
+            r#"{{"message":"unneeded `return` statement","code":{{"code":"clippy::needless_return"}},"level":"error","spans":[],"children":[{{"message":"remove `return`","code":null,"level":"help","spans":[{}]}},{{"message":"overlapping","code":null,"level":"help","spans":[{}]}},{{"message":"unsure","code":null,"level":"help","spans":[{}]}}]}}"#,
+            span(25, 35, "42", "MachineApplicable"),
+            span(25, 31, "", "MachineApplicable"),
+            span(0, 2, "pub fn", "MaybeIncorrect"),
+        );
+        let (diagnostics, _) = diagnostics::parse(&messages, Path::new(""));
+        let fixes = plan(
+            Path::new("tests/fixture/lints/needlessReturn.rs"),
+            &diagnostics,
+        )
+        .unwrap();
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(
+            fixes[0].new,
+            "fn answer() -> u32 {\n    42\n}\n\nfn main() {\n    println!(\"{}\", answer());\n}\n"
+        );
+
+        // Suggestions for files that aren't part of the exercise are left out
+        let fixes = plan(
+            Path::new("tests/fixture/lints/manyArguments.rs"),
+            &diagnostics,
+        )
+        .unwrap();
+        assert!(fixes.is_empty());
+    }
+
+    #[test]
+    fn test_apply() {
+        let replace = |start, end, text: &str| Replacement {
+            start,
+            end,
+            text: text.to_string(),
+        };
+        assert_eq!(
+            apply("let x = 1;", vec![replace(8, 9, "2"), replace(4, 5, "y")]).as_deref(),
+            Some("let y = 2;")
+        );
+        assert_eq!(apply("é", vec![replace(1, 1, "e")]), None);
+        assert_eq!(apply("x", vec![replace(1, 3, "")]), None);
+    }
+}
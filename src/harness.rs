@@ -0,0 +1,281 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+// The outcome of a single test of a test harness
+#[derive(Serialize, Copy, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+// A single test of a test harness, as parsed from the harness' output
+#[derive(Serialize, Debug, PartialEq)]
+pub struct TestCase {
+    // The full path of the test function, e.g. `tests::it_works`
+    pub name: String,
+    pub status: TestStatus,
+    // The message the test panicked with, if it failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panic: Option<String>,
+    // Both sides of a failed `assert_eq!` or `assert_ne!`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<String>,
+}
+
+// How many tests of a test harness passed, failed or were ignored
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq)]
+pub struct TestCounts {
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+}
+
+impl TestCounts {
+    // The number of tests that were actually run
+    pub fn total(&self) -> usize {
+        self.passed + self.failed
+    }
+}
+
+impl Display for TestCounts {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(&format!("{}/{}", self.passed, self.total()))
+    }
+}
+
+// Everything that could be learned from the output of a test harness
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct TestReport {
+    #[serde(flatten)]
+    pub counts: TestCounts,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tests: Vec<TestCase>,
+}
+
+impl TestReport {
+    pub fn failures(&self) -> impl Iterator<Item = &TestCase> {
+        self.tests.iter().filter(|t| t.status == TestStatus::Failed)
+    }
+}
+
+// Parse the output of a libtest harness run with `--show-output`.
+// Every `test <name> ... <result>` line becomes a test case, and the panic
+// message and assertion values of failed tests are picked up from the
+// `---- <name> stdout ----` sections that follow.
+pub fn parse(output: &str) -> TestReport {
+    let mut report = TestReport::default();
+    let mut sections: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut section: Option<&str> = None;
+
+    for line in output.lines() {
+        if let Some(rest) = line.strip_prefix("test ") {
+            if let Some((name, result)) = rest.rsplit_once(" ... ") {
+                let name = name.trim_end_matches(" - should panic");
+                let status = match result {
+                    "ok" => TestStatus::Passed,
+                    "FAILED" => TestStatus::Failed,
+                    r if r.starts_with("ignored") => TestStatus::Ignored,
+                    _ => continue,
+                };
+                report.tests.push(TestCase {
+                    name: name.to_string(),
+                    status,
+                    panic: None,
+                    left: None,
+                    right: None,
+                });
+                continue;
+            }
+        }
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|l| l.strip_suffix(" stdout ----"))
+        {
+            section = Some(name);
+            continue;
+        }
+        if line == "successes:" || line == "failures:" {
+            section = None;
+            continue;
+        }
+        if let Some(name) = section {
+            sections.entry(name).or_default().push(line);
+        }
+    }
+
+    for test in report.tests.iter_mut() {
+        match test.status {
+            TestStatus::Passed => report.counts.passed += 1,
+            TestStatus::Ignored => report.counts.ignored += 1,
+            TestStatus::Failed => {
+                report.counts.failed += 1;
+                if let Some(lines) = sections.get(test.name.as_str()) {
+                    parse_panic(test, lines);
+                }
+            }
+        }
+    }
+    report
+}
+
+// Find the panic message and the values of a failed assertion
+// in the captured output of a single test
+fn parse_panic(test: &mut TestCase, lines: &[&str]) {
+    // Recent versions of Rust print the thread id after its name:
+    // thread 'main' (1234) panicked at ...
+    let start = match lines
+        .iter()
+        .position(|l| l.starts_with("thread '") && l.contains(" panicked at "))
+    {
+        Some(start) => start,
+        None => return,
+    };
+    let header = lines[start];
+    let location = &header[header.find(" panicked at ").unwrap() + " panicked at ".len()..];
+
+    let message = if let Some(quoted) = location.strip_prefix('\'') {
+        // Before Rust 1.73 the message was quoted on the same line:
+        // thread 'main' panicked at 'message', src/main.rs:1:1
+        let mut message = vec![quoted];
+        if let Some(end) = quoted.rfind("', ") {
+            message[0] = &quoted[..end];
+        } else {
+            for line in &lines[start + 1..] {
+                match line.rfind("', ") {
+                    Some(end) => {
+                        message.push(&line[..end]);
+                        break;
+                    }
+                    None => message.push(line),
+                }
+            }
+        }
+        message
+    } else {
+        // Since then it follows on the lines below:
+        // thread 'main' panicked at src/main.rs:1:1:
+        // message
+        lines[start + 1..]
+            .iter()
+            .take_while(|l| {
+                !l.is_empty() && !l.starts_with("note: ") && !l.starts_with("stack backtrace:")
+            })
+            .copied()
+            .collect()
+    };
+
+    let mut panic = Vec::new();
+    for line in message {
+        let trimmed = line.trim_start();
+        if let Some(left) = trimmed.strip_prefix("left: ") {
+            test.left = Some(clean_value(left));
+        } else if let Some(right) = trimmed.strip_prefix("right: ") {
+            test.right = Some(clean_value(right));
+        } else {
+            panic.push(line);
+        }
+    }
+    test.panic = Some(panic.join("\n"));
+}
+
+// Older versions of Rust printed assertion values as `value`,
+fn clean_value(value: &str) -> String {
+    value
+        .trim_end_matches(',')
+        .trim_end_matches('`')
+        .trim_start_matches('`')
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const OUTPUT: &str = "
+running 4 tests
+test tests::a ... ok
+test tests::b ... FAILED
+test tests::c ... ignored
+test tests::e - should panic ... ok
+
+successes:
+
+---- tests::a stdout ----
+hi
+
+
+successes:
+    tests::a
+    tests::e
+
+failures:
+
+---- tests::b stdout ----
+
+thread 'tests::b' (14348) panicked at t.rs:6:14:
+assertion `left == right` failed: math is hard
+  left: 2
+ right: 3
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    tests::b
+
+test result: FAILED. 2 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.01s
+";
+
+    #[test]
+    fn test_parse_counts() {
+        let report = parse(OUTPUT);
+        assert_eq!(
+            report.counts,
+            TestCounts {
+                passed: 2,
+                failed: 1,
+                ignored: 1
+            }
+        );
+        assert_eq!(report.counts.to_string(), "2/3");
+        assert_eq!(report.tests[3].name, "tests::e");
+    }
+
+    #[test]
+    fn test_parse_failure() {
+        let report = parse(OUTPUT);
+        let failure = report.failures().next().unwrap();
+        assert_eq!(failure.name, "tests::b");
+        assert_eq!(
+            failure.panic.as_deref(),
+            Some("assertion `left == right` failed: math is hard")
+        );
+        assert_eq!(failure.left.as_deref(), Some("2"));
+        assert_eq!(failure.right.as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn test_parse_old_panic_format() {
+        let output = "test it_works ... FAILED
+
+failures:
+
+---- it_works stdout ----
+thread 'it_works' panicked at 'assertion failed: `(left == right)`
+  left: `1`,
+ right: `2`', src/lib.rs:3:5
+";
+        let report = parse(output);
+        let failure = report.failures().next().unwrap();
+        assert_eq!(
+            failure.panic.as_deref(),
+            Some("assertion failed: `(left == right)`")
+        );
+        assert_eq!(failure.left.as_deref(), Some("1"));
+        assert_eq!(failure.right.as_deref(), Some("2"));
+    }
+}
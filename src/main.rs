@@ -1,26 +1,52 @@
-use crate::exercise::{Exercise, ExerciseList};
+use crate::cache::CACHE_DIR;
+use crate::exercise::{CompileOptions, Exercise, Limits};
+use crate::pack::Manifest;
+use crate::progress::{Progress, Status, PROGRESS_FILE};
+use crate::report::{write_line, Format, Record, Reporter};
+use crate::reset::ResetResult;
 use crate::run::run;
-use crate::verify::verify;
+use crate::scheduler::Scheduler;
+use crate::shell::ShellCommand;
+use crate::validate::INFO_FILE;
+use crate::verify::{check, verify, verify_all, verify_with_report, OutcomeKind};
+use crate::watcher::{Change, FileWatcher, Watched};
 use argh::FromArgs;
-use console::Emoji;
-use notify::DebouncedEvent;
-use notify::{RecommendedWatcher, RecursiveMode, Watcher};
-use std::ffi::OsStr;
+use console::{style, Emoji};
+use std::collections::VecDeque;
 use std::fs;
-use std::io::{self, prelude::*};
-use std::path::Path;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::sync::mpsc::channel;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::Duration;
 
 #[macro_use]
 mod ui;
 
+mod cache;
+mod config;
+mod diagnostics;
+mod diff;
 mod exercise;
+mod explain;
+mod fix;
+mod harness;
+mod markdown;
+mod pack;
+mod progress;
+mod report;
+mod reset;
 mod run;
+mod scheduler;
+mod shell;
+mod tui;
+mod validate;
 mod verify;
+mod watcher;
+mod workspace;
 
 // In sync with crate version
 const VERSION: &str = "4.5.0";
@@ -34,6 +60,28 @@ struct Args {
     /// show the executable version
     #[argh(switch, short = 'v')]
     version: bool,
+    /// when to color the output: auto, always or never (auto colors it in
+    /// a terminal, unless NO_COLOR is set)
+    #[argh(option)]
+    color: Option<config::ColorMode>,
+    /// stop exercise binaries after this many seconds, 0 disables the timeout
+    /// (overrides the timeouts in info.toml)
+    #[argh(option)]
+    timeout: Option<u64>,
+    /// limit the memory of exercise binaries to this many MiB (Linux only)
+    #[argh(option)]
+    memory_limit: Option<u64>,
+    /// limit the CPU time of exercise binaries to this many seconds (Linux only)
+    #[argh(option)]
+    cpu_limit: Option<u64>,
+    /// compile every exercise from scratch, instead of reusing earlier
+    /// compilations of the same sources
+    #[argh(switch)]
+    no_cache: bool,
+    /// load the exercise pack in this directory next to the stock exercises,
+    /// can be given more than once
+    #[argh(option)]
+    pack: Vec<PathBuf>,
     #[argh(subcommand)]
     nested: Option<Subcommands>,
 }
@@ -45,18 +93,47 @@ enum Subcommands {
     Watch(WatchArgs),
     Run(RunArgs),
     Hint(HintArgs),
+    Explain(ExplainArgs),
     List(ListArgs),
+    Solution(SolutionArgs),
+    Diff(DiffArgs),
+    Fix(FixArgs),
+    Reset(ResetArgs),
+    Check(CheckArgs),
+    Tui(TuiArgs),
+    Cache(CacheArgs),
+    Config(ConfigArgs),
 }
 
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "verify")]
 /// Verifies all exercises according to the recommended order
-struct VerifyArgs {}
+struct VerifyArgs {
+    #[argh(switch, short = 'a')]
+    /// check every exercise in parallel, without stopping at the first failure
+    all: bool,
+    #[argh(option, short = 'j')]
+    /// number of exercises to check at the same time with --all
+    /// (defaults to the number of CPUs)
+    jobs: Option<usize>,
+    #[argh(option, default = "Format::Text")]
+    /// output format: text, json or jsonl
+    format: Format,
+}
 
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "watch")]
 /// Reruns `verify` when files were edited
-struct WatchArgs {}
+struct WatchArgs {
+    #[argh(option, default = "200")]
+    /// how long to wait for more changes after a file was saved before
+    /// verifying it, in milliseconds (defaults to 200)
+    debounce: u64,
+    #[argh(switch)]
+    /// look for changes by polling, for filesystems the file watcher of the
+    /// system misses changes on
+    poll: bool,
+}
 
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "run")]
@@ -65,17 +142,142 @@ struct RunArgs {
     #[argh(positional)]
     /// the name of the exercise
     name: String,
+    #[argh(option, default = "Format::Text")]
+    /// output format: text, json or jsonl
+    format: Format,
 }
 
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "hint")]
-/// Returns a hint for the given exercise
+/// Reveals the next hint for the given exercise, along with the ones revealed before
 struct HintArgs {
     #[argh(positional)]
     /// the name of the exercise
     name: String,
 }
 
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "explain")]
+/// Explains a compiler error code, or the ones the next pending exercise fails with
+struct ExplainArgs {
+    #[argh(positional)]
+    /// an error code like E0382, or a clippy lint like clippy::needless_return
+    code: Option<String>,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "solution")]
+/// Shows the reference solution of an exercise once it is solved or all of its hints were revealed
+struct SolutionArgs {
+    #[argh(positional)]
+    /// the name of the exercise
+    name: String,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "diff")]
+/// Shows the differences between an exercise and its reference solution
+struct DiffArgs {
+    #[argh(positional)]
+    /// the name of the exercise
+    name: String,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "fix")]
+/// Shows the fixes the compiler and clippy are sure about for an exercise, and applies them if you agree
+struct FixArgs {
+    #[argh(positional)]
+    /// the name of the exercise
+    name: String,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "reset")]
+/// Restores exercises to their original state, backing up your version first
+struct ResetArgs {
+    #[argh(positional)]
+    /// the name of the exercise
+    name: Option<String>,
+    #[argh(switch, short = 'a')]
+    /// reset every exercise
+    all: bool,
+    #[argh(option, short = 'f')]
+    /// reset the exercises matching a string, comma separated patterns are acceptable
+    filter: Option<String>,
+    #[argh(switch)]
+    /// bring back your version of the exercises from before the last reset
+    undo: bool,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "check")]
+/// Checks info.toml and the exercises it lists for mistakes
+struct CheckArgs {}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "tui")]
+/// Browses and works on the exercises in a full-screen terminal interface
+struct TuiArgs {}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "cache")]
+/// Manages the cache of compiled exercises
+struct CacheArgs {
+    #[argh(subcommand)]
+    nested: CacheSubcommands,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+enum CacheSubcommands {
+    Clean(CacheCleanArgs),
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "clean")]
+/// Removes every cached compilation
+struct CacheCleanArgs {}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "config")]
+/// Shows and changes your preferences
+struct ConfigArgs {
+    #[argh(subcommand)]
+    nested: ConfigSubcommands,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+enum ConfigSubcommands {
+    Get(ConfigGetArgs),
+    Set(ConfigSetArgs),
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "get")]
+/// Shows every preference, where it is set and what it is about, or the value of one
+struct ConfigGetArgs {
+    #[argh(positional)]
+    /// the name of the preference
+    key: Option<String>,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "set")]
+/// Sets a preference in your config file
+struct ConfigSetArgs {
+    #[argh(positional)]
+    /// the name of the preference
+    key: String,
+    #[argh(positional)]
+    /// its new value
+    value: String,
+    #[argh(switch)]
+    /// set it in rustlings.toml instead, for this rustlings directory only
+    local: bool,
+}
+
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "list")]
 /// Lists the exercises available in Rustlings
@@ -96,6 +298,9 @@ struct ListArgs {
     #[argh(switch, short = 's')]
     /// display only exercises that have been solved
     solved: bool,
+    #[argh(option, default = "Format::Text")]
+    /// output format: text, json or jsonl
+    format: Format,
 }
 
 fn main() {
@@ -118,7 +323,30 @@ fn main() {
         println!();
     }
 
-    if !Path::new("info.toml").exists() {
+    let command_line = config::Layer {
+        verbose: args.nocapture.then_some(true),
+        color: args.color,
+        timeout: args.timeout,
+        ..config::Layer::default()
+    };
+    let layers = config::Layers::load(command_line).unwrap_or_else(|e| {
+        println!("{}", e);
+        std::process::exit(1);
+    });
+    config::init(layers.resolve());
+    if let Some(Subcommands::Config(subargs)) = &args.nested {
+        configure(subargs, &layers).unwrap_or_else(|e| {
+            println!("{}", e);
+            std::process::exit(1);
+        });
+        std::process::exit(0);
+    }
+
+    let manifests = pack::discover(&args.pack).unwrap_or_else(|e| {
+        println!("{}", e);
+        std::process::exit(1);
+    });
+    if manifests.is_empty() {
         println!(
             "{} must be run from the rustlings directory",
             std::env::current_exe().unwrap().to_str().unwrap()
@@ -133,10 +361,33 @@ fn main() {
         println!("For instructions on how to install Rust, check the README.");
         std::process::exit(1);
     }
+    workspace::sweep();
 
-    let toml_str = &fs::read_to_string("info.toml").unwrap();
-    let exercises = toml::from_str::<ExerciseList>(toml_str).unwrap().exercises;
-    let verbose = args.nocapture;
+    let limits = Limits {
+        timeout: args.timeout,
+        memory_limit: args.memory_limit,
+        cpu_limit: args.cpu_limit,
+    };
+    let options = CompileOptions {
+        cancel: None,
+        cache: if args.no_cache {
+            None
+        } else {
+            Some(PathBuf::from(CACHE_DIR))
+        },
+    };
+    let exercises = load_exercises(&manifests, &limits).unwrap_or_else(|errors| {
+        for error in &errors {
+            println!("{}", error);
+        }
+        std::process::exit(1);
+    });
+    let mut progress = Progress::load(PROGRESS_FILE).unwrap_or_else(|e| {
+        println!("Failed to read your progress from {}: {}", PROGRESS_FILE, e);
+        println!("Remove the file to start over.");
+        std::process::exit(1);
+    });
+    let verbose = config::get().verbose;
 
     let command = args.nested.unwrap_or_else(|| {
         let text = fs::read_to_string("default_out.txt").unwrap();
@@ -145,86 +396,320 @@ fn main() {
     });
     match command {
         Subcommands::List(subargs) => {
-            if !subargs.paths && !subargs.names {
-                println!("{:<17}\t{:<46}\t{:<7}", "Name", "Path", "Status");
+            let text = subargs.format == Format::Text;
+            let mut reporter = Reporter::new(subargs.format);
+            if text && !subargs.paths && !subargs.names {
+                println!(
+                    "{:<17}\t{:<46}\t{:<7}\t{:<5}\tHints",
+                    "Name", "Path", "Status", "Tests"
+                );
             }
             let mut exercises_done: u16 = 0;
+            // The number of exercises done and in total for every pack, in order
+            let mut packs: Vec<(&str, usize, usize)> = Vec::new();
+            // The same for every topic, named after their pack unless it's the stock one
+            let mut topics: Vec<(String, usize, usize)> = Vec::new();
             let filters = subargs.filter.clone().unwrap_or_default().to_lowercase();
             exercises.iter().for_each(|e| {
                 let fname = format!("{}", e.path.display());
-                let filter_cond = filters
-                    .split(',')
-                    .filter(|f| !f.trim().is_empty())
-                    .any(|f| e.name.contains(&f) || fname.contains(&f));
-                let status = if e.looks_done() {
+                let filter_cond = matches_filter(e, &filters);
+                let status = progress.status(e);
+                let done = status == Status::Done;
+                if done {
                     exercises_done += 1;
-                    "Done"
-                } else {
-                    "Pending"
-                };
+                }
+                let new_pack = packs.last().map_or(true, |p| p.0 != e.pack_name());
+                if new_pack {
+                    packs.push((e.pack_name(), 0, 0));
+                }
+                let pack = packs.last_mut().unwrap();
+                pack.1 += usize::from(done);
+                pack.2 += 1;
+                if let Some(topic) = topic_label(e) {
+                    if topics.last().map_or(true, |t| t.0 != topic) {
+                        topics.push((topic, 0, 0));
+                    }
+                    let topic = topics.last_mut().unwrap();
+                    topic.1 += usize::from(done);
+                    topic.2 += 1;
+                }
                 let solve_cond = {
-                    (e.looks_done() && subargs.solved)
-                        || (!e.looks_done() && subargs.unsolved)
+                    (done && subargs.solved)
+                        || (!done && subargs.unsolved)
                         || (!subargs.solved && !subargs.unsolved)
                 };
                 if solve_cond && (filter_cond || subargs.filter.is_none()) {
+                    if !text {
+                        reporter.count(status_counter(status));
+                        reporter.exercise(Record::new(e, &progress));
+                        return;
+                    }
                     let line = if subargs.paths {
-                        format!("{}\n", fname)
+                        fname
                     } else if subargs.names {
-                        format!("{}\n", e.name)
+                        e.name.clone()
                     } else {
-                        format!("{:<17}\t{:<46}\t{:<7}\n", e.name, fname, status)
+                        if new_pack && manifests.len() > 1 {
+                            println!("{}", style(e.pack_name()).bold());
+                        }
+                        let tests = progress
+                            .get(e)
+                            .and_then(|p| p.tests)
+                            .map(|counts| counts.to_string())
+                            .unwrap_or_default();
+                        let levels = e.hint_levels().len();
+                        let hints = if levels > 0 {
+                            format!("{}/{}", progress.hints_revealed(e), levels)
+                        } else {
+                            String::new()
+                        };
+                        format!(
+                            "{:<17}\t{:<46}\t{:<7}\t{:<5}\t{}",
+                            e.name, fname, status, tests, hints
+                        )
                     };
-                    // Somehow using println! leads to the binary panicking
-                    // when its output is piped.
-                    // So, we're handling a Broken Pipe error and exiting with 0 anyway
-                    let stdout = std::io::stdout();
-                    {
-                        let mut handle = stdout.lock();
-                        handle.write_all(line.as_bytes()).unwrap_or_else(|e| {
-                            match e.kind() {
-                                std::io::ErrorKind::BrokenPipe => std::process::exit(0),
-                                _ => std::process::exit(1),
-                            };
-                        });
-                    }
+                    write_line(&line);
                 }
             });
-            let percentage_progress = exercises_done as f32 / exercises.len() as f32 * 100.0;
-            println!(
-                "Progress: You completed {} / {} exercises ({:.2} %).",
-                exercises_done,
-                exercises.len(),
-                percentage_progress
-            );
+            if !text {
+                reporter.finish();
+                std::process::exit(0);
+            }
+            print_progress(exercises_done as usize, exercises.len());
+            if packs.len() > 1 {
+                for (name, done, total) in packs {
+                    println!("  {}: {} / {}", name, done, total);
+                }
+            }
+            if !topics.is_empty() {
+                println!("Topics:");
+                let width = topics.iter().map(|t| t.0.len()).max().unwrap_or(0);
+                for (name, done, total) in topics {
+                    println!(
+                        "  {:<width$}  {} {} / {}",
+                        name,
+                        progress_bar(done, total),
+                        done,
+                        total
+                    );
+                }
+            }
             std::process::exit(0);
         }
 
         Subcommands::Run(subargs) => {
-            let exercise = find_exercise(&subargs.name, &exercises);
+            let exercise = find_exercise(&subargs.name, &exercises, &progress);
 
-            run(exercise, verbose).unwrap_or_else(|_| std::process::exit(1));
+            if subargs.format == Format::Text {
+                if subargs.name == "next" && starts_topic(exercise, &exercises, &progress) {
+                    introduce_topic(exercise);
+                }
+                run(exercise, &mut progress, verbose, &options)
+                    .unwrap_or_else(|_| std::process::exit(1));
+            } else {
+                let mut reporter = Reporter::new(subargs.format);
+                let outcome = check(exercise, &options);
+                let passed = outcome.kind == OutcomeKind::Success;
+                if let Some(report) = &outcome.tests {
+                    progress.record_tests(exercise, report.counts);
+                    let _ = progress.save();
+                }
+                reporter.count(if passed { "passed" } else { "failed" });
+                reporter.exercise(Record::new(exercise, &progress).with_outcome(outcome));
+                reporter.finish();
+                if !passed {
+                    std::process::exit(1);
+                }
+            }
         }
 
         Subcommands::Hint(subargs) => {
-            let exercise = find_exercise(&subargs.name, &exercises);
+            let exercise = find_exercise(&subargs.name, &exercises, &progress);
 
-            println!("{}", exercise.hint);
+            reveal_hint(
+                exercise,
+                &mut progress,
+                &format!("run `rustlings hint {}` again", exercise.name),
+            );
         }
 
-        Subcommands::Verify(_subargs) => {
-            verify(&exercises, verbose).unwrap_or_else(|_| std::process::exit(1));
+        Subcommands::Explain(subargs) => {
+            let codes = match &subargs.code {
+                Some(code) => explain::normalize(code).map(|code| vec![code]).ok_or_else(|| {
+                    format!(
+                        "`{}` is neither an error code like E0382 nor a lint like clippy::needless_return.",
+                        code
+                    )
+                }),
+                None => codes_to_explain(find_exercise("next", &exercises, &progress), &options),
+            };
+            match codes.and_then(|codes| explain::explain(&codes, options.cache.as_deref())) {
+                Ok(lines) => explain::page(&lines),
+                Err(e) => {
+                    println!("{}", e);
+                    std::process::exit(1);
+                }
+            }
         }
 
-        Subcommands::Watch(_subargs) => {
-            if let Err(e) = watch(&exercises, verbose) {
-                println!(
-                    "Error: Could not watch your progress. Error message was {:?}.",
-                    e
-                );
-                println!("Most likely you've run out of disk space or your 'inotify limit' has been reached.");
+        Subcommands::Solution(subargs) => {
+            let exercise = find_exercise(&subargs.name, &exercises, &progress);
+            let solution = read_solution(exercise, &progress);
+
+            print!("{}", solution);
+        }
+
+        Subcommands::Fix(subargs) => {
+            let exercise = find_exercise(&subargs.name, &exercises, &progress);
+            if !fix_exercise(exercise, &mut progress, &options) {
                 std::process::exit(1);
             }
+        }
+
+        Subcommands::Diff(subargs) => {
+            let exercise = find_exercise(&subargs.name, &exercises, &progress);
+            let solution = read_solution(exercise, &progress);
+            let source = fs::read_to_string(&exercise.path).unwrap_or_else(|e| {
+                println!("Failed to read {}: {}", exercise.path.display(), e);
+                std::process::exit(1)
+            });
+
+            let solution_path = exercise.solution.as_ref().unwrap();
+            if !diff::print_unified(
+                &exercise.path.display().to_string(),
+                &solution_path.display().to_string(),
+                &source,
+                &solution,
+            ) {
+                println!("{} is the same as the reference solution.", exercise.name);
+            }
+        }
+
+        Subcommands::Check(_subargs) => {
+            let diagnostics: Vec<_> = manifests
+                .iter()
+                .flat_map(|m| m.check(&m.exercises().unwrap_or_default()))
+                .collect();
+            if diagnostics.is_empty() {
+                success!("All {} exercises look good!", exercises.len());
+            } else {
+                for diagnostic in &diagnostics {
+                    println!("{}", diagnostic);
+                }
+                warn!("Found {} problem(s)", diagnostics.len());
+                std::process::exit(1);
+            }
+        }
+
+        Subcommands::Reset(subargs) => {
+            let selected: Vec<&Exercise> = match (&subargs.name, subargs.all, &subargs.filter) {
+                (Some(name), false, None) => vec![find_exercise(name, &exercises, &progress)],
+                (None, true, None) => exercises.iter().collect(),
+                (None, false, Some(filter)) => {
+                    let filters = filter.to_lowercase();
+                    exercises
+                        .iter()
+                        .filter(|e| matches_filter(e, &filters))
+                        .collect()
+                }
+                _ => {
+                    println!("Pass exactly one of an exercise name, --all or --filter.");
+                    std::process::exit(1)
+                }
+            };
+            let mut failed = false;
+            for exercise in selected {
+                let result = if subargs.undo {
+                    reset::undo(exercise)
+                } else {
+                    reset::reset(exercise)
+                };
+                failed |= !report_reset(exercise, result);
+            }
+            if failed {
+                std::process::exit(1);
+            }
+        }
+
+        Subcommands::Verify(subargs) => {
+            if subargs.all {
+                let jobs = subargs.jobs.unwrap_or_else(|| {
+                    thread::available_parallelism()
+                        .map(|n| n.get())
+                        .unwrap_or(1)
+                });
+                let mut reporter = Reporter::new(subargs.format);
+                let failures = verify_all(&exercises, &mut progress, jobs, &mut reporter, &options);
+                reporter.finish();
+                if failures > 0 {
+                    std::process::exit(1);
+                }
+            } else if subargs.format == Format::Text {
+                verify(&exercises, &mut progress, verbose, &options)
+                    .unwrap_or_else(|_| std::process::exit(1));
+            } else {
+                let mut reporter = Reporter::new(subargs.format);
+                let result = verify_with_report(&exercises, &mut progress, &mut reporter, &options);
+                reporter.finish();
+                result.unwrap_or_else(|_| std::process::exit(1));
+            }
+        }
+
+        Subcommands::Cache(subargs) => match subargs.nested {
+            CacheSubcommands::Clean(_) => match cache::clean(Path::new(CACHE_DIR)) {
+                Ok(entries) => println!("Removed {} cached compilation(s).", entries),
+                Err(e) => {
+                    println!("Failed to remove {}: {}", CACHE_DIR, e);
+                    std::process::exit(1);
+                }
+            },
+        },
+
+        // Handled before the exercises are loaded
+        Subcommands::Config(_) => unreachable!(),
+
+        Subcommands::Tui(_subargs) => {
+            if !io::stdout().is_terminal() {
+                println!("`rustlings tui` has to be run in a terminal.");
+                std::process::exit(1);
+            }
+            if let Err(e) = tui::tui(&exercises, &mut progress, &options) {
+                println!("Error: The terminal interface failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+
+        Subcommands::Watch(subargs) => {
+            let extra_packs = &args.pack;
+            let load = || {
+                let manifests = pack::discover(extra_packs).map_err(|e| vec![e.to_string()])?;
+                if manifests.is_empty() {
+                    return Err(vec![format!(
+                        "There is no {} to load exercises from.",
+                        INFO_FILE
+                    )]);
+                }
+                let exercises = load_exercises(&manifests, &limits)?;
+                Ok((manifests, exercises))
+            };
+            match watch(
+                manifests, exercises, load, progress, &options, verbose, &subargs,
+            ) {
+                Ok(WatchStatus::Finished { skipped: 0 }) => {}
+                Ok(WatchStatus::Finished { skipped }) => {
+                    println!(
+                        "All other exercises are done, only the {} you skipped are left.",
+                        skipped
+                    );
+                    println!("Run `rustlings list --unsolved` to find them.");
+                    std::process::exit(0);
+                }
+                Ok(WatchStatus::Quit | WatchStatus::Reload) => std::process::exit(0),
+                Err(e) => {
+                    println!("Error: Could not watch the exercises for changes: {}.", e);
+                    std::process::exit(1);
+                }
+            }
             println!(
                 "{emoji} All exercises completed! {emoji}",
                 emoji = Emoji("🎉", "★")
@@ -262,34 +747,206 @@ fn main() {
     }
 }
 
-fn spawn_watch_shell(failed_exercise_hint: &Arc<Mutex<Option<String>>>) {
-    let failed_exercise_hint = Arc::clone(failed_exercise_hint);
-    println!("Type 'hint' or open the corresponding README.md file to get help or type 'clear' to clear the screen.");
-    thread::spawn(move || loop {
-        let mut input = String::new();
-        match io::stdin().read_line(&mut input) {
-            Ok(_) => {
-                let input = input.trim();
-                if input.eq("hint") {
-                    if let Some(hint) = &*failed_exercise_hint.lock().unwrap() {
-                        println!("{}", hint);
-                    }
-                } else if input.eq("clear") {
-                    println!("\x1B[2J\x1B[1;1H");
-                } else {
-                    println!("unknown command: {}", input);
-                }
-            }
-            Err(error) => println!("error reading command: {}", error),
+// The summary counter an exercise with the given status is counted in
+fn status_counter(status: Status) -> &'static str {
+    match status {
+        Status::Done => "done",
+        Status::Stale => "stale",
+        Status::Pending => "pending",
+        Status::Skipped => "skipped",
+    }
+}
+
+// Reveal the next hint of the exercise and print every hint revealed so far.
+// `again` tells how to ask for the next hint, if there are more.
+fn reveal_hint(exercise: &Exercise, progress: &mut Progress, again: &str) {
+    let levels = exercise.hint_levels();
+    if levels.is_empty() {
+        println!("There are no hints for {}.", exercise.name);
+        return;
+    }
+    let revealed = progress.reveal_hint(exercise);
+    if let Err(e) = progress.save() {
+        warn!("Failed to save your progress: {}", e);
+    }
+    if levels.len() == 1 {
+        println!("{}", levels[0]);
+        return;
+    }
+    for (i, hint) in levels.iter().take(revealed).enumerate() {
+        println!(
+            "{}",
+            style(format!("Hint {}/{}:", i + 1, levels.len())).bold()
+        );
+        println!("{}", hint.trim_end());
+        println!();
+    }
+    if revealed < levels.len() {
+        println!(
+            "{} more hint(s) left, {} to reveal the next one.",
+            levels.len() - revealed,
+            again
+        );
+    }
+}
+
+// Show the fixes the compiler suggests for the exercise and apply them once
+// the learner agreed to, returns false if that failed
+fn fix_exercise(exercise: &Exercise, progress: &mut Progress, options: &CompileOptions) -> bool {
+    let output = match exercise.compile(options) {
+        Ok(_) => {
+            println!("{} compiles, there is nothing to fix.", exercise.name);
+            return true;
+        }
+        Err(output) => output,
+    };
+    let fixes = match fix::plan(&exercise.path, &output.diagnostics) {
+        Ok(fixes) => fixes,
+        Err(e) => {
+            println!("Failed to work out the fixes for {}: {}", exercise.name, e);
+            return false;
+        }
+    };
+    if fixes.is_empty() {
+        println!(
+            "The compiler has no fixes for {} it is sure about, `rustlings run {}` shows what it suggests.",
+            exercise.name, exercise.name
+        );
+        return true;
+    }
+    for fix in &fixes {
+        let path = fix.path.display().to_string();
+        diff::print_unified(&path, &path, &fix.old, &fix.new);
+    }
+    print!("Apply these changes to {}? [y/N] ", exercise.name);
+    let _ = io::stdout().flush();
+    let mut answer = String::new();
+    let _ = io::stdin().read_line(&mut answer);
+    if !matches!(answer.trim(), "y" | "Y" | "yes") {
+        println!("Nothing was changed.");
+        return true;
+    }
+    for fix in &fixes {
+        if let Err(e) = fs::write(&fix.path, &fix.new) {
+            println!("Failed to write {}: {}", fix.path.display(), e);
+            return false;
+        }
+    }
+    progress.record_fix(exercise);
+    if let Err(e) = progress.save() {
+        warn!("Failed to save your progress: {}", e);
+    }
+    println!(
+        "Applied the fixes, `rustlings run {}` shows whether it passes now.",
+        exercise.name
+    );
+    true
+}
+
+fn print_progress(done: usize, total: usize) {
+    let percentage_progress = done as f32 / total as f32 * 100.0;
+    println!(
+        "Progress: You completed {} / {} exercises ({:.2} %).",
+        done, total, percentage_progress
+    );
+}
+
+// Tell how resetting or restoring the exercise went, returns false if it failed
+fn report_reset(exercise: &Exercise, result: io::Result<ResetResult>) -> bool {
+    match result {
+        Ok(ResetResult::Reset) => success!("Reset {}", exercise.name),
+        Ok(ResetResult::Restored) => success!("Restored your version of {}", exercise.name),
+        Ok(ResetResult::AlreadyPristine) => {
+            println!("{} is already in its original state", exercise.name)
+        }
+        Ok(ResetResult::NoBackup) => {
+            println!("There is no backup of {} to restore", exercise.name)
         }
+        Err(e) => {
+            warn!("Failed to reset {}", format!("{}: {}", exercise.name, e));
+            return false;
+        }
+    }
+    true
+}
+
+// The name a topic is listed under, if the exercise has one
+fn topic_label(exercise: &Exercise) -> Option<String> {
+    let topic = exercise.topic.as_ref()?;
+    Some(match &exercise.pack {
+        Some(pack) => format!("{}/{}", pack.name, topic),
+        None => topic.clone(),
+    })
+}
+
+fn progress_bar(done: usize, total: usize) -> String {
+    const WIDTH: usize = 20;
+    let filled = (done * WIDTH).checked_div(total).unwrap_or(0);
+    format!(
+        "[{}{}]",
+        style("#".repeat(filled)).green(),
+        "-".repeat(WIDTH - filled)
+    )
+}
+
+// Whether working on the exercise means entering its topic,
+// that is none of the exercises of the topic is done yet
+fn starts_topic(exercise: &Exercise, exercises: &[Exercise], progress: &Progress) -> bool {
+    let topic = match topic_label(exercise) {
+        Some(topic) => topic,
+        None => return false,
+    };
+    !exercises
+        .iter()
+        .any(|e| topic_label(e).as_ref() == Some(&topic) && progress.is_done(e))
+}
+
+// Print the README of the exercise's topic, if it has one
+fn introduce_topic(exercise: &Exercise) {
+    let readme = match exercise.readme().and_then(|r| fs::read_to_string(r).ok()) {
+        Some(readme) => readme,
+        None => return,
+    };
+    for line in markdown::render(&readme) {
+        println!("{}", line);
+    }
+    println!();
+}
+
+// Whether the exercise's name or path contains any of the comma separated patterns
+fn matches_filter(exercise: &Exercise, filters: &str) -> bool {
+    let fname = format!("{}", exercise.path.display());
+    filters
+        .split(',')
+        .filter(|f| !f.trim().is_empty())
+        .any(|f| exercise.name.contains(f) || fname.contains(f))
+}
+
+// Read the reference solution of the exercise, exiting if there is none
+// or if the learner hasn't earned a look at it yet
+fn read_solution(exercise: &Exercise, progress: &Progress) -> String {
+    let path = exercise.solution.as_ref().unwrap_or_else(|| {
+        println!("There is no reference solution for {}.", exercise.name);
+        std::process::exit(1)
     });
+    if !progress.solution_unlocked(exercise) {
+        println!(
+            "The solution for {} is still locked. Solve the exercise first, or reveal all of its hints with `rustlings hint {}`.",
+            exercise.name, exercise.name
+        );
+        std::process::exit(1)
+    }
+    fs::read_to_string(path).unwrap_or_else(|e| {
+        println!("Failed to read {}: {}", path.display(), e);
+        std::process::exit(1)
+    })
 }
 
-fn find_exercise<'a>(name: &str, exercises: &'a [Exercise]) -> &'a Exercise {
+fn find_exercise<'a>(name: &str, exercises: &'a [Exercise], progress: &Progress) -> &'a Exercise {
     if name.eq("next") {
         exercises
             .iter()
-            .find(|e| !e.looks_done())
+            .find(|e| progress.is_pending(e))
             .unwrap_or_else(|| {
                 println!("🎉 Congratulations! You have done all the exercises!");
                 println!("🔚 There are no more exercises to do next!");
@@ -306,61 +963,451 @@ fn find_exercise<'a>(name: &str, exercises: &'a [Exercise]) -> &'a Exercise {
     }
 }
 
-fn watch(exercises: &[Exercise], verbose: bool) -> notify::Result<()> {
+// The error codes and clippy lints the exercise fails to compile with, the
+// lints of rustc have no explanation. Its last compilation is usually taken
+// from the cache.
+fn codes_to_explain(exercise: &Exercise, options: &CompileOptions) -> Result<Vec<String>, String> {
+    let output = match exercise.compile(options) {
+        Ok(_) => {
+            return Err(format!(
+                "{} compiles, there are no errors to explain.",
+                exercise.name
+            ))
+        }
+        Err(output) => output,
+    };
+    let codes: Vec<String> = output
+        .codes()
+        .into_iter()
+        .chain(output.lints())
+        .filter(|code| explain::normalize(code).as_ref() == Some(code))
+        .collect();
+    if codes.is_empty() {
+        Err(format!(
+            "The errors of {} have no error codes to explain.",
+            exercise.name
+        ))
+    } else {
+        Ok(codes)
+    }
+}
+
+// Show or set the preferences as `config` was told to
+fn configure(args: &ConfigArgs, layers: &config::Layers) -> Result<(), String> {
+    match &args.nested {
+        ConfigSubcommands::Get(subargs) => match &subargs.key {
+            Some(key) => {
+                if !config::KEYS.iter().any(|(k, _)| k == key) {
+                    return Err(format!(
+                        "There is no preference called `{}`, `rustlings config get` lists them all.",
+                        key
+                    ));
+                }
+                // Nothing for a preference without a value, like the timeout
+                if let Some((value, _)) = layers.lookup(key) {
+                    match value.as_str() {
+                        Some(text) => println!("{}", text),
+                        None => println!("{}", value),
+                    }
+                }
+            }
+            None => {
+                for (key, about) in config::KEYS {
+                    match layers.lookup(key) {
+                        Some((value, source)) => {
+                            let setting = format!("{} = {}", key, value);
+                            println!("{:<28} ({})", setting, source);
+                        }
+                        None => println!("{:<28} (not set)", key),
+                    }
+                    println!("    {}", style(about).dim());
+                }
+            }
+        },
+        ConfigSubcommands::Set(subargs) => {
+            let path = if subargs.local {
+                PathBuf::from(pack::CONFIG_FILE)
+            } else {
+                config::user_config_path()
+                    .ok_or("Cannot tell where your config file goes, set $XDG_CONFIG_HOME.")?
+            };
+            config::set(&path, &subargs.key, &subargs.value)?;
+            println!("Set `{}` in {}.", subargs.key, path.display());
+        }
+    }
+    Ok(())
+}
+
+// Load the exercises of every manifest, with the limits given on the command
+// line and the timeout preferred in the config
+fn load_exercises(manifests: &[Manifest], limits: &Limits) -> Result<Vec<Exercise>, Vec<String>> {
+    let mut exercises = Vec::new();
+    for manifest in manifests {
+        exercises.extend(manifest.exercises().map_err(|diagnostics| {
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.to_string())
+                .chain(Some(format!(
+                    "Rustlings could not load the exercises from {}.",
+                    manifest.path().display()
+                )))
+                .collect::<Vec<_>>()
+        })?);
+    }
+    for exercise in &mut exercises {
+        exercise.limits.default_timeout(config::get().timeout);
+        exercise.limits.override_with(limits);
+    }
+    Ok(exercises)
+}
+
+// What ended `watch`
+enum WatchStatus {
+    // Every exercise is done, apart from the given number of skipped ones
+    Finished { skipped: usize },
+    // The learner quit from the shell
+    Quit,
+    // A manifest changed, so the exercises have to be loaded again
+    Reload,
+}
+
+// What `watch` reacts to
+enum WatchEvent {
+    Change(Change),
+    Command(ShellCommand),
+}
+
+// What lasts for the whole of `watch`, across reloads of the exercises
+struct WatchSession {
+    rx: Receiver<WatchEvent>,
+    // Events that came in while waiting for a burst of writes to end
+    backlog: VecDeque<WatchEvent>,
+    // Raised as soon as a file changes, so that a verification in progress
+    // that is outdated by the change stops early
+    cancel: Arc<AtomicBool>,
+    // How the exercises are compiled, stopped by `cancel`
+    options: CompileOptions,
+    debounce: Duration,
+    verbose: bool,
+}
+
+impl WatchSession {
+    // Wait until nothing changed for the debounce time, as editors often
+    // write a file several times in a row when saving. Adds the Rust files
+    // written in the meantime to `files`, and returns whether a manifest was.
+    fn settle(&mut self, files: &mut Vec<PathBuf>) -> bool {
+        let mut manifest = false;
+        loop {
+            match self.rx.recv_timeout(self.debounce) {
+                Ok(WatchEvent::Change(Change::Source(file))) => files.push(file),
+                Ok(WatchEvent::Change(Change::Manifest)) => manifest = true,
+                Ok(event) => self.backlog.push_back(event),
+                Err(_) => return manifest,
+            }
+        }
+    }
+}
+
+// The exercise directories and the manifests of the packs, along with
+// rustlings.toml that lists the packs
+fn watched_paths(manifests: &[Manifest]) -> Watched {
+    Watched {
+        dirs: manifests.iter().map(|m| m.exercises_dir()).collect(),
+        files: manifests
+            .iter()
+            .map(|m| m.path())
+            .chain(Some(PathBuf::from(pack::CONFIG_FILE)))
+            .collect(),
+    }
+}
+
+fn watch(
+    mut manifests: Vec<Manifest>,
+    mut exercises: Vec<Exercise>,
+    load: impl Fn() -> Result<(Vec<Manifest>, Vec<Exercise>), Vec<String>>,
+    mut progress: Progress,
+    options: &CompileOptions,
+    verbose: bool,
+    args: &WatchArgs,
+) -> notify::Result<WatchStatus> {
+    let (tx, rx) = channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+
+    let (change_tx, change_rx) = channel();
+    let mut watcher = FileWatcher::new(watched_paths(&manifests), args.poll, change_tx.clone())?;
+    let changes = tx.clone();
+    let cancel_on_change = Arc::clone(&cancel);
+    thread::spawn(move || {
+        for change in change_rx {
+            cancel_on_change.store(true, Ordering::SeqCst);
+            if changes.send(WatchEvent::Change(change)).is_err() {
+                return;
+            }
+        }
+    });
+
+    let _terminal_mode = shell::TerminalMode::save();
+    let names = Arc::new(RwLock::new(Vec::new()));
+    shell::spawn(Arc::clone(&names), tx, WatchEvent::Command);
+
+    let mut session = WatchSession {
+        rx,
+        backlog: VecDeque::new(),
+        options: CompileOptions {
+            cancel: Some(Arc::clone(&cancel)),
+            ..options.clone()
+        },
+        cancel,
+        debounce: Duration::from_millis(args.debounce),
+        verbose,
+    };
+    let mut notice = Vec::new();
+    loop {
+        if let Some(reason) = &watcher.polling {
+            notice.push(format!("Polling for changes, as {}.", reason));
+        }
+        *names.write().unwrap() = exercises.iter().map(|e| e.name.clone()).collect();
+        match watch_exercises(&mut session, &exercises, &mut progress, &notice) {
+            WatchStatus::Reload => {}
+            status => return Ok(status),
+        }
+        notice.clear();
+        match load() {
+            Ok(loaded) => {
+                (manifests, exercises) = loaded;
+                watcher =
+                    FileWatcher::new(watched_paths(&manifests), args.poll, change_tx.clone())?;
+                notice.push(format!(
+                    "Reloaded the exercises, found {}.",
+                    exercises.len()
+                ));
+            }
+            Err(errors) => {
+                notice = errors;
+                notice.push(String::from(
+                    "Kept the exercises from before, fix the manifest to load it again.",
+                ));
+            }
+        }
+    }
+}
+
+// Verify the exercises and again whenever one of them is edited, until they
+// are all done, the learner quits or the exercises have to be reloaded
+fn watch_exercises(
+    session: &mut WatchSession,
+    exercises: &[Exercise],
+    progress: &mut Progress,
+    notice: &[String],
+) -> WatchStatus {
     /* Clears the terminal with an ANSI escape code.
     Works in UNIX and newer Windows terminals. */
     fn clear_screen() {
         println!("\x1Bc");
     }
 
-    let (tx, rx) = channel();
+    // Verify the exercises in order until one fails. `None` if a file changed
+    // in the meantime, so that the result is outdated.
+    fn verify_queue<'a>(
+        queue: &[&'a Exercise],
+        progress: &mut Progress,
+        cancel: &AtomicBool,
+        verbose: bool,
+        options: &CompileOptions,
+    ) -> Option<Result<(), &'a Exercise>> {
+        cancel.store(false, Ordering::SeqCst);
+        let result = verify(queue.iter().copied(), progress, verbose, options);
+        if cancel.load(Ordering::SeqCst) {
+            None
+        } else {
+            Some(result)
+        }
+    }
 
-    let mut watcher: RecommendedWatcher = Watcher::new(tx, Duration::from_secs(2))?;
-    watcher.watch(Path::new("./exercises"), RecursiveMode::Recursive)?;
+    let cancel = Arc::clone(&session.cancel);
+    let verbose = session.verbose;
+    let options = session.options.clone();
+    let mut scheduler = Scheduler::new(exercises);
 
+    let finished = |progress: &Progress| WatchStatus::Finished {
+        skipped: exercises
+            .iter()
+            .filter(|e| progress.status(e) == Status::Skipped)
+            .count(),
+    };
     clear_screen();
 
-    let to_owned_hint = |t: &Exercise| t.hint.to_owned();
-    let failed_exercise_hint = match verify(exercises.iter(), verbose) {
-        Ok(_) => return Ok(()),
-        Err(exercise) => Arc::new(Mutex::new(Some(to_owned_hint(exercise)))),
+    let queue = scheduler.queue(&[], progress);
+    let mut result = verify_queue(&queue, progress, &cancel, verbose, &options);
+    for exercise in queue {
+        scheduler.invalidate(exercise);
+    }
+    // The topic whose README was shown last, so that it is only shown once
+    let mut introduced = None;
+    let mut introduce = |exercise: &Exercise, progress: &Progress| {
+        if topic_label(exercise) != introduced && starts_topic(exercise, exercises, progress) {
+            introduce_topic(exercise);
+            introduced = topic_label(exercise);
+        }
     };
-    spawn_watch_shell(&failed_exercise_hint);
+
+    if result.as_ref().map_or(true, |result| result.is_err()) {
+        println!("Type 'hint' or open the corresponding README.md file to get help, or 'help' for the other commands.");
+    }
+    for line in notice {
+        println!("{}", line);
+    }
+    // Whether the last verification was given up on, and has to be done again
+    let mut interrupted = false;
     loop {
-        match rx.recv() {
-            Ok(event) => match event {
-                DebouncedEvent::Create(b) | DebouncedEvent::Chmod(b) | DebouncedEvent::Write(b) => {
-                    if b.extension() == Some(OsStr::new("rs")) && b.exists() {
-                        let filepath = b.as_path().canonicalize().unwrap();
-                        let pending_exercises = exercises
-                            .iter()
-                            .skip_while(|e| !filepath.ends_with(&e.path))
-                            // .filter(|e| filepath.ends_with(&e.path))
-                            .chain(
-                                exercises
-                                    .iter()
-                                    .filter(|e| !e.looks_done() && !filepath.ends_with(&e.path)),
-                            );
-                        clear_screen();
-                        match verify(pending_exercises, verbose) {
-                            Ok(_) => return Ok(()),
-                            Err(exercise) => {
-                                let mut failed_exercise_hint = failed_exercise_hint.lock().unwrap();
-                                *failed_exercise_hint = Some(to_owned_hint(exercise));
-                            }
+        match result.take() {
+            Some(Ok(_)) => return finished(progress),
+            Some(Err(exercise)) => {
+                introduce(exercise, progress);
+                scheduler.set_current(exercise);
+                interrupted = false;
+            }
+            None => {}
+        }
+        // The exercise the learner is working on, the first one that failed
+        let current = match scheduler.current() {
+            Some(current) => current,
+            None => exercises
+                .iter()
+                .find(|e| progress.is_pending(e))
+                .unwrap_or(&exercises[0]),
+        };
+
+        let event = match session.backlog.pop_front() {
+            Some(event) => event,
+            None => match session.rx.recv() {
+                Ok(event) => event,
+                Err(e) => {
+                    println!("watch error: {:?}", e);
+                    return WatchStatus::Quit;
+                }
+            },
+        };
+        let queue = match event {
+            WatchEvent::Change(change) => {
+                let mut files = Vec::new();
+                let mut reload = match change {
+                    Change::Source(file) => {
+                        files.push(file);
+                        false
+                    }
+                    Change::Manifest => true,
+                };
+                reload |= session.settle(&mut files);
+                if reload {
+                    return WatchStatus::Reload;
+                }
+                let edited = scheduler.edited(&files);
+                if edited.is_empty() && !interrupted {
+                    continue;
+                }
+                // Editing a skipped exercise takes it up again
+                for exercise in &edited {
+                    progress.set_skipped(exercise, false);
+                    scheduler.invalidate(exercise);
+                }
+                clear_screen();
+                scheduler.queue(&edited, progress)
+            }
+            WatchEvent::Command(command) => match command {
+                ShellCommand::Hint => {
+                    reveal_hint(current, progress, "type 'hint' again");
+                    continue;
+                }
+                ShellCommand::Explain(code) => {
+                    cancel.store(false, Ordering::SeqCst);
+                    let codes = match code {
+                        Some(code) => explain::normalize(&code)
+                            .map(|code| vec![code])
+                            .ok_or_else(|| format!("`{}` is not an error code", code)),
+                        None => codes_to_explain(current, &options),
+                    };
+                    // Not paged, as the shell is waiting for input on the terminal
+                    match codes.and_then(|codes| explain::explain(&codes, options.cache.as_deref()))
+                    {
+                        Ok(lines) => lines.iter().for_each(|line| println!("{}", line)),
+                        Err(e) => println!("{}", e),
+                    }
+                    continue;
+                }
+                ShellCommand::Clear => {
+                    println!("\x1B[2J\x1B[1;1H");
+                    continue;
+                }
+                ShellCommand::List => {
+                    for exercise in exercises {
+                        println!("{:<17}\t{}", exercise.name, progress.status(exercise));
+                    }
+                    continue;
+                }
+                ShellCommand::Run(name) => {
+                    let name = name.unwrap_or_else(|| current.name.clone());
+                    let exercise = match name.as_str() {
+                        "next" => exercises.iter().find(|e| progress.is_pending(e)),
+                        _ => exercises.iter().find(|e| e.name == name),
+                    };
+                    match exercise {
+                        Some(exercise) => {
+                            cancel.store(false, Ordering::SeqCst);
+                            let _ = run(exercise, progress, verbose, &options);
+                            scheduler.invalidate(exercise);
                         }
+                        None => println!("No exercise found for '{}'!", name),
                     }
+                    continue;
+                }
+                ShellCommand::Skip => {
+                    progress.set_skipped(current, true);
+                    scheduler.invalidate(current);
+                    if let Err(e) = progress.save() {
+                        warn!("Failed to save your progress: {}", e);
+                    }
+                    clear_screen();
+                    println!("Skipped {}, edit it to get back to it.", current.name);
+                    scheduler.queue(&[], progress)
+                }
+                ShellCommand::Reset => {
+                    // The reset file is verified again by the watcher
+                    report_reset(current, reset::reset(current));
+                    continue;
+                }
+                ShellCommand::Progress => {
+                    let done = exercises.iter().filter(|e| progress.is_done(e)).count();
+                    print_progress(done, exercises.len());
+                    continue;
+                }
+                ShellCommand::Help => {
+                    for (command, description) in shell::COMMANDS {
+                        println!("  {:<10}{}", command, description);
+                    }
+                    continue;
+                }
+                ShellCommand::Quit => return WatchStatus::Quit,
+                ShellCommand::Unknown(input) => {
+                    println!(
+                        "unknown command: {}, type 'help' for a list of commands",
+                        input
+                    );
+                    continue;
                 }
-                _ => {}
             },
-            Err(e) => println!("watch error: {:?}", e),
+        };
+        result = verify_queue(&queue, progress, &cancel, verbose, &options);
+        interrupted = result.is_none();
+        for exercise in queue {
+            scheduler.invalidate(exercise);
         }
     }
 }
 
 fn rustc_exists() -> bool {
     Command::new("rustc")
-        .args(&["--version"])
+        .args(["--version"])
         .stdout(Stdio::null())
         .spawn()
         .and_then(|mut child| child.wait())
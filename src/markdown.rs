@@ -0,0 +1,113 @@
+use crate::config;
+use console::style;
+use regex::{Captures, Regex};
+
+// Render the Markdown of a topic README for the terminal. Only what the
+// READMEs of the exercises use is supported: headings, lists, code blocks,
+// inline code, emphasis and links.
+pub fn render(source: &str) -> Vec<String> {
+    let inline = Inline::new();
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            lines.push(format!("    {}", config::get().theme.accent(style(line))));
+        } else if let Some(heading) = trimmed.strip_prefix("# ") {
+            lines.push(
+                style(inline.render(heading))
+                    .bold()
+                    .underlined()
+                    .to_string(),
+            );
+        } else if trimmed.starts_with('#') {
+            let heading = trimmed.trim_start_matches('#').trim_start();
+            lines.push(style(inline.render(heading)).bold().to_string());
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            let indent = &line[..line.len() - trimmed.len()];
+            lines.push(format!("{}  • {}", indent, inline.render(item)));
+        } else {
+            lines.push(inline.render(line));
+        }
+    }
+    lines
+}
+
+// The Markdown that can appear within a line
+struct Inline {
+    code: Regex,
+    strong: Regex,
+    link: Regex,
+}
+
+impl Inline {
+    fn new() -> Self {
+        Inline {
+            code: Regex::new(r"`([^`]+)`").unwrap(),
+            strong: Regex::new(r"\*\*([^*]+)\*\*").unwrap(),
+            link: Regex::new(r"\[([^\]]+)\]\(([^)]+)\)").unwrap(),
+        }
+    }
+
+    fn render(&self, text: &str) -> String {
+        let text = self.code.replace_all(text, |c: &Captures| {
+            config::get()
+                .theme
+                .accent(style(c[1].to_string()))
+                .to_string()
+        });
+        let text = self.strong.replace_all(&text, |c: &Captures| {
+            style(c[1].to_string()).bold().to_string()
+        });
+        let text = self.link.replace_all(&text, |c: &Captures| {
+            format!("{} ({})", &c[1], style(c[2].to_string()).dim())
+        });
+        text.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use console::strip_ansi_codes;
+
+    fn render_plain(source: &str) -> Vec<String> {
+        render(source)
+            .iter()
+            .map(|line| strip_ansi_codes(line).into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_blocks() {
+        let source = "# Variables\n\nUse `let`.\n\n## Further information\n\n- [Book](https://doc.rust-lang.org/book/)\n";
+        assert_eq!(
+            render_plain(source),
+            vec![
+                "Variables",
+                "",
+                "Use let.",
+                "",
+                "Further information",
+                "",
+                "  • Book (https://doc.rust-lang.org/book/)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_code_block() {
+        let source = "```rust\nlet x = 5; // [not](a link)\n```\n**done**";
+        assert_eq!(
+            render_plain(source),
+            vec!["    let x = 5; // [not](a link)", "done"]
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::exercise::Exercise;
+use crate::validate::{self, Diagnostic, EXERCISES_DIR, INFO_FILE};
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The file in the rustlings directory additional exercise packs are listed in
+pub const CONFIG_FILE: &str = "rustlings.toml";
+// The name the stock exercises are grouped under next to other packs
+pub const STOCK_PACK: &str = "rustlings";
+
+#[derive(Deserialize, Default)]
+struct Config {
+    // Directories of exercise packs, relative to the rustlings directory
+    #[serde(default)]
+    packs: Vec<PathBuf>,
+}
+
+// The top-level keys of an info.toml, next to its `[[exercises]]`
+#[derive(Deserialize, Default)]
+struct Header {
+    // The name the exercises of the pack are namespaced with
+    name: Option<String>,
+}
+
+// An exercise pack other than the stock exercises. Its names are prefixed
+// with the pack's name, e.g. `async/futures1`, and its paths are relative
+// to the directory of its manifest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pack {
+    pub name: String,
+    pub dir: PathBuf,
+}
+
+impl Pack {
+    // The path of an exercise file relative to the pack's directory
+    pub fn relative<'a>(&self, path: &'a Path) -> &'a Path {
+        path.strip_prefix(&self.dir).unwrap_or(path)
+    }
+}
+
+// An info.toml exercises are loaded from
+pub struct Manifest {
+    // The pack the manifest belongs to, `None` for the stock exercises
+    pub pack: Option<Pack>,
+    pub source: String,
+}
+
+impl Manifest {
+    fn read(path: &Path) -> io::Result<Manifest> {
+        let source = fs::read_to_string(path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Failed to read {}: {}", path.display(), e),
+            )
+        })?;
+        Ok(Manifest { pack: None, source })
+    }
+
+    pub fn path(&self) -> PathBuf {
+        match &self.pack {
+            Some(pack) => pack.dir.join(INFO_FILE),
+            None => PathBuf::from(INFO_FILE),
+        }
+    }
+
+    pub fn exercises(&self) -> Result<Vec<Exercise>, Vec<Diagnostic>> {
+        let mut exercises = validate::parse(&self.source).map_err(|d| self.locate(d))?;
+        if let Some(pack) = &self.pack {
+            for exercise in &mut exercises {
+                exercise.name = format!("{}/{}", pack.name, exercise.name);
+                exercise.path = pack.dir.join(&exercise.path);
+                exercise.solution = exercise.solution.as_ref().map(|s| pack.dir.join(s));
+                exercise.lints.config = exercise.lints.config.as_ref().map(|c| pack.dir.join(c));
+                exercise.pack = Some(pack.clone());
+            }
+        }
+        Ok(exercises)
+    }
+
+    // The directory the manifest's exercises are in
+    pub fn exercises_dir(&self) -> PathBuf {
+        match &self.pack {
+            Some(pack) => pack.dir.join(EXERCISES_DIR),
+            None => PathBuf::from(EXERCISES_DIR),
+        }
+    }
+
+    // Check the manifest and the exercises loaded from it, see `validate::check`
+    pub fn check(&self, exercises: &[Exercise]) -> Vec<Diagnostic> {
+        self.locate(validate::check(
+            &self.source,
+            exercises,
+            &self.exercises_dir(),
+        ))
+    }
+
+    // Point the diagnostics about info.toml at this manifest
+    fn locate(&self, mut diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        for diagnostic in &mut diagnostics {
+            if diagnostic.file == Path::new(INFO_FILE) {
+                diagnostic.file = self.path();
+            }
+        }
+        diagnostics
+    }
+}
+
+// Find the manifests to load exercises from: the stock info.toml in the
+// current directory, if there is one, followed by the packs listed in
+// rustlings.toml and the ones passed on the command line.
+pub fn discover(extra: &[PathBuf]) -> io::Result<Vec<Manifest>> {
+    let mut manifests = Vec::new();
+    if Path::new(INFO_FILE).exists() {
+        manifests.push(Manifest::read(Path::new(INFO_FILE))?);
+    }
+
+    let config = match fs::read_to_string(CONFIG_FILE) {
+        Ok(contents) => toml::from_str::<Config>(&contents).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to read {}: {}", CONFIG_FILE, e),
+            )
+        })?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+        Err(e) => return Err(e),
+    };
+
+    for dir in config.packs.iter().chain(extra) {
+        let path = dir.join(INFO_FILE);
+        let mut manifest = Manifest::read(&path)?;
+        let name = toml::from_str::<Header>(&manifest.source)
+            .ok()
+            .and_then(|h| h.name)
+            .or_else(|| {
+                fs::canonicalize(dir)
+                    .ok()
+                    .and_then(|d| d.file_name().map(|n| n.to_string_lossy().into_owned()))
+            })
+            .unwrap_or_else(|| dir.display().to_string());
+        if manifests
+            .iter()
+            .any(|m| m.pack.as_ref().is_some_and(|p| p.name == name))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("There is more than one exercise pack named '{}'", name),
+            ));
+        }
+        manifest.pack = Some(Pack {
+            name,
+            dir: dir.clone(),
+        });
+        manifests.push(manifest);
+    }
+    Ok(manifests)
+}
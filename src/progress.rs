@@ -0,0 +1,316 @@
+use crate::exercise::{Exercise, Mode, State};
+use crate::harness::TestCounts;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// The file, relative to the rustlings directory, progress is persisted in
+pub const PROGRESS_FILE: &str = ".rustlings-state";
+
+// A successful verification of an exercise
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Verification {
+    // Seconds since the Unix epoch at which the exercise last verified
+    pub at: u64,
+    // The mode the exercise was verified in
+    pub mode: Mode,
+    // The hash of the source code that passed, see `Exercise::source_hash`
+    pub hash: String,
+}
+
+// Everything that is remembered about a single exercise
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ExerciseProgress {
+    // How many of the exercise's hints have been shown so far
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub hints_revealed: usize,
+    // The last successful verification, if there was one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified: Option<Verification>,
+    // The results of the last time the exercise's tests were run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tests: Option<TestCounts>,
+    // Whether the learner chose to move on without solving the exercise
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub skipped: bool,
+    // How many times the fixes suggested by the compiler were applied with `fix`
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub fixes_applied: usize,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+// The progress of an exercise as seen by `list`, `run next` and `watch`
+#[derive(Serialize, Copy, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    // The exercise verified and its source hasn't changed since
+    Done,
+    // The exercise verified once, but its source has been edited afterwards
+    Stale,
+    // The exercise never verified, or still carries the `I AM NOT DONE` marker
+    Pending,
+    // The learner skipped the exercise, and it isn't done
+    Skipped,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let status = match self {
+            Status::Done => "Done",
+            Status::Stale => "Stale",
+            Status::Pending => "Pending",
+            Status::Skipped => "Skipped",
+        };
+        f.pad(status)
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct ProgressFile {
+    #[serde(default)]
+    exercises: BTreeMap<String, ExerciseProgress>,
+}
+
+// The progress database, keyed by the exercise names from info.toml
+pub struct Progress {
+    path: PathBuf,
+    exercises: BTreeMap<String, ExerciseProgress>,
+}
+
+impl Progress {
+    // Load the progress stored at the given path.
+    // A missing file is treated as no progress at all.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Progress> {
+        let path = path.into();
+        let file = match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str::<ProgressFile>(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => ProgressFile::default(),
+            Err(e) => return Err(e),
+        };
+        Ok(Progress {
+            path,
+            exercises: file.exercises,
+        })
+    }
+
+    // Persist the progress. The file is written next to its destination
+    // first and then renamed, so a concurrent reader never sees half of it.
+    pub fn save(&self) -> io::Result<()> {
+        let file = ProgressFile {
+            exercises: self.exercises.clone(),
+        };
+        let contents =
+            toml::to_string(&file).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let temp_path = PathBuf::from(format!("{}.{}.tmp", self.path.display(), process::id()));
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, &self.path)
+    }
+
+    pub fn get(&self, exercise: &Exercise) -> Option<&ExerciseProgress> {
+        self.exercises.get(&exercise.name)
+    }
+
+    // Remember that the exercise, as it is currently on disk, verified successfully
+    pub fn record_success(&mut self, exercise: &Exercise) {
+        let at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let progress = self.exercises.entry(exercise.name.clone()).or_default();
+        progress.verified = Some(Verification {
+            at,
+            mode: exercise.mode,
+            hash: exercise.source_hash(),
+        });
+        progress.skipped = false;
+    }
+
+    // Mark the exercise as skipped, so that `verify`, `watch` and `run next`
+    // pass over it, or take it up again
+    pub fn set_skipped(&mut self, exercise: &Exercise, skipped: bool) {
+        self.exercises
+            .entry(exercise.name.clone())
+            .or_default()
+            .skipped = skipped;
+    }
+
+    // Remember the results of running the exercise's tests, passing or not
+    pub fn record_tests(&mut self, exercise: &Exercise, counts: TestCounts) {
+        self.exercises
+            .entry(exercise.name.clone())
+            .or_default()
+            .tests = Some(counts);
+    }
+
+    // Remember that the fixes the compiler suggested were applied to the exercise
+    pub fn record_fix(&mut self, exercise: &Exercise) {
+        self.exercises
+            .entry(exercise.name.clone())
+            .or_default()
+            .fixes_applied += 1;
+    }
+
+    // How many of the exercise's hints have been shown so far
+    pub fn hints_revealed(&self, exercise: &Exercise) -> usize {
+        self.get(exercise).map_or(0, |p| p.hints_revealed)
+    }
+
+    // Reveal the next hint of the exercise, if there is one left,
+    // and return how many hints have been revealed now
+    pub fn reveal_hint(&mut self, exercise: &Exercise) -> usize {
+        let levels = exercise.hint_levels().len();
+        let progress = self.exercises.entry(exercise.name.clone()).or_default();
+        progress.hints_revealed = (progress.hints_revealed + 1).min(levels);
+        progress.hints_revealed
+    }
+
+    // The reference solution may be looked at once the exercise verified,
+    // or once the learner asked for every one of its hints
+    pub fn solution_unlocked(&self, exercise: &Exercise) -> bool {
+        let levels = exercise.hint_levels().len();
+        self.get(exercise)
+            .and_then(|p| p.verified.as_ref())
+            .is_some()
+            || (levels > 0 && self.hints_revealed(exercise) >= levels)
+    }
+
+    pub fn status(&self, exercise: &Exercise) -> Status {
+        let status = match self.get(exercise).and_then(|p| p.verified.as_ref()) {
+            None => Status::Pending,
+            Some(verification)
+                if verification.mode != exercise.mode
+                    || verification.hash != exercise.source_hash() =>
+            {
+                Status::Stale
+            }
+            Some(_) if exercise.state() == State::Done => Status::Done,
+            Some(_) => Status::Pending,
+        };
+        if status != Status::Done && self.get(exercise).is_some_and(|p| p.skipped) {
+            Status::Skipped
+        } else {
+            status
+        }
+    }
+
+    pub fn is_done(&self, exercise: &Exercise) -> bool {
+        self.status(exercise) == Status::Done
+    }
+
+    // Whether the exercise is still to be done, that is neither done nor skipped
+    pub fn is_pending(&self, exercise: &Exercise) -> bool {
+        !matches!(self.status(exercise), Status::Done | Status::Skipped)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::exercise::{Limits, Lints};
+
+    fn exercise(path: &str) -> Exercise {
+        Exercise {
+            name: "example".into(),
+            path: PathBuf::from(path),
+            solution: None,
+            mode: Mode::Compile,
+            topic: None,
+            hint: String::new(),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: None,
+            pack: None,
+        }
+    }
+
+    #[test]
+    fn test_unverified_exercise_is_pending() {
+        let progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        let exercise = exercise("tests/fixture/state/finished_exercise.rs");
+        assert_eq!(progress.status(&exercise), Status::Pending);
+    }
+
+    #[test]
+    fn test_verified_exercise_is_done() {
+        let mut progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        let exercise = exercise("tests/fixture/state/finished_exercise.rs");
+        progress.record_success(&exercise);
+        assert_eq!(progress.status(&exercise), Status::Done);
+    }
+
+    #[test]
+    fn test_verified_exercise_with_marker_is_pending() {
+        let mut progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        let exercise = exercise("tests/fixture/state/pending_exercise.rs");
+        progress.record_success(&exercise);
+        assert_eq!(progress.status(&exercise), Status::Pending);
+    }
+
+    #[test]
+    fn test_hints_are_revealed_one_at_a_time() {
+        let mut progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        let mut exercise = exercise("tests/fixture/state/finished_exercise.rs");
+        exercise.hints = vec![String::from("first"), String::from("second")];
+        assert_eq!(progress.hints_revealed(&exercise), 0);
+        assert_eq!(progress.reveal_hint(&exercise), 1);
+        assert_eq!(progress.reveal_hint(&exercise), 2);
+        assert_eq!(progress.reveal_hint(&exercise), 2);
+        assert_eq!(progress.hints_revealed(&exercise), 2);
+    }
+
+    #[test]
+    fn test_solution_unlocks() {
+        let mut progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        let mut exercise = exercise("tests/fixture/state/finished_exercise.rs");
+        exercise.hint = String::from("only hint");
+        assert!(!progress.solution_unlocked(&exercise));
+        progress.reveal_hint(&exercise);
+        assert!(progress.solution_unlocked(&exercise));
+
+        let mut progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        progress.record_success(&exercise);
+        assert!(progress.solution_unlocked(&exercise));
+    }
+
+    #[test]
+    fn test_edited_exercise_is_stale() {
+        let mut progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        let exercise = exercise("tests/fixture/state/finished_exercise.rs");
+        progress.record_success(&exercise);
+        progress
+            .exercises
+            .get_mut("example")
+            .unwrap()
+            .verified
+            .as_mut()
+            .unwrap()
+            .hash = String::from("0000000000000000");
+        assert_eq!(progress.status(&exercise), Status::Stale);
+    }
+
+    #[test]
+    fn test_skipped_exercise_until_verified() {
+        let mut progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        let exercise = exercise("tests/fixture/state/finished_exercise.rs");
+        progress.set_skipped(&exercise, true);
+        assert_eq!(progress.status(&exercise), Status::Skipped);
+        assert!(!progress.is_pending(&exercise));
+        progress.record_success(&exercise);
+        assert_eq!(progress.status(&exercise), Status::Done);
+        assert!(!progress.get(&exercise).unwrap().skipped);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::exercise::{Exercise, Mode, State};
+use crate::harness::TestReport;
+use crate::progress::{Progress, Status};
+use crate::verify::{Outcome, OutcomeKind};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Instant;
+
+// How `list`, `verify` and `run` present their results
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Format {
+    // Styled text meant to be read by humans
+    #[default]
+    Text,
+    // A single JSON document written once everything is done
+    Json,
+    // One JSON object per line, written as soon as each exercise is done
+    JsonLines,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "jsonl" | "json-lines" => Ok(Format::JsonLines),
+            _ => Err(format!(
+                "unknown format '{}', expected one of 'text', 'json' or 'jsonl'",
+                s
+            )),
+        }
+    }
+}
+
+// The machine-readable description of a single exercise
+#[derive(Serialize)]
+pub struct Record<'a> {
+    pub name: &'a str,
+    // The exercise pack the exercise is from, if it isn't one of the stock exercises
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pack: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<&'a str>,
+    pub path: &'a Path,
+    pub mode: Mode,
+    pub status: Status,
+    pub state: State,
+    // How compiling and running the exercise ended, if it was attempted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<OutcomeKind>,
+    // The results of the exercise's tests, from this run or the last one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tests: Option<TestReport>,
+    // How many of the exercise's hints have been revealed, if it has any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hints: Option<HintCount>,
+    // How many times the compiler's fixes were applied to the exercise with `fix`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixes_applied: Option<usize>,
+    // The lints clippy denied the exercise for
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub lints: Vec<String>,
+    // The error codes of the compiler's errors, e.g. `E0382`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub codes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u128>,
+}
+
+#[derive(Serialize)]
+pub struct HintCount {
+    pub revealed: usize,
+    pub total: usize,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(exercise: &'a Exercise, progress: &Progress) -> Self {
+        Record {
+            name: &exercise.name,
+            pack: exercise.pack.as_ref().map(|p| p.name.as_str()),
+            topic: exercise.topic.as_deref(),
+            path: &exercise.path,
+            mode: exercise.mode,
+            status: progress.status(exercise),
+            state: exercise.state(),
+            outcome: None,
+            tests: progress
+                .get(exercise)
+                .and_then(|p| p.tests)
+                .map(|counts| TestReport {
+                    counts,
+                    tests: Vec::new(),
+                }),
+            hints: match exercise.hint_levels().len() {
+                0 => None,
+                total => Some(HintCount {
+                    revealed: progress.hints_revealed(exercise),
+                    total,
+                }),
+            },
+            fixes_applied: progress
+                .get(exercise)
+                .map(|p| p.fixes_applied)
+                .filter(|&fixes| fixes > 0),
+            lints: Vec::new(),
+            codes: Vec::new(),
+            stdout: None,
+            stderr: None,
+            duration_ms: None,
+        }
+    }
+
+    pub fn with_outcome(mut self, outcome: Outcome) -> Self {
+        self.outcome = Some(outcome.kind);
+        if outcome.tests.is_some() {
+            self.tests = outcome.tests;
+        }
+        self.lints = outcome.output.lints();
+        self.codes = outcome.output.codes();
+        self.stdout = Some(outcome.output.stdout);
+        self.stderr = Some(outcome.output.stderr);
+        self.duration_ms = Some(outcome.duration.as_millis());
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct Summary<'a> {
+    total: usize,
+    #[serde(flatten)]
+    counts: &'a BTreeMap<&'static str, usize>,
+    duration_ms: u128,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Line<'a> {
+    Exercise(&'a Record<'a>),
+    Summary(Summary<'a>),
+}
+
+#[derive(Serialize)]
+struct Document<'a> {
+    exercises: &'a [Record<'a>],
+    summary: Summary<'a>,
+}
+
+// Collects exercise records and writes them out in the requested format.
+// With `Format::Text` nothing is written, the caller prints on its own.
+pub struct Reporter<'a> {
+    format: Format,
+    records: Vec<Record<'a>>,
+    counts: BTreeMap<&'static str, usize>,
+    started: Instant,
+}
+
+impl<'a> Reporter<'a> {
+    pub fn new(format: Format) -> Self {
+        Reporter {
+            format,
+            records: Vec::new(),
+            counts: BTreeMap::new(),
+            started: Instant::now(),
+        }
+    }
+
+    // Whether the caller is expected to print human-readable output itself
+    pub fn is_text(&self) -> bool {
+        self.format == Format::Text
+    }
+
+    // Add one to the given counter of the final summary
+    pub fn count(&mut self, counter: &'static str) {
+        *self.counts.entry(counter).or_insert(0) += 1;
+    }
+
+    pub fn exercise(&mut self, record: Record<'a>) {
+        match self.format {
+            Format::Text => {}
+            Format::Json => self.records.push(record),
+            Format::JsonLines => {
+                write_line(&serde_json::to_string(&Line::Exercise(&record)).unwrap());
+                self.records.push(record);
+            }
+        }
+    }
+
+    // Write out the summary, and for `Format::Json` everything collected before it
+    pub fn finish(self) {
+        let summary = Summary {
+            total: self.records.len(),
+            counts: &self.counts,
+            duration_ms: self.started.elapsed().as_millis(),
+        };
+        let line = match self.format {
+            Format::Text => return,
+            Format::Json => serde_json::to_string_pretty(&Document {
+                exercises: &self.records,
+                summary,
+            }),
+            Format::JsonLines => serde_json::to_string(&Line::Summary(summary)),
+        };
+        write_line(&line.unwrap());
+    }
+}
+
+// Somehow using println! leads to the binary panicking
+// when its output is piped.
+// So, we're handling a Broken Pipe error and exiting with 0 anyway
+pub fn write_line(line: &str) {
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+    writeln!(handle, "{}", line).unwrap_or_else(|e| {
+        match e.kind() {
+            io::ErrorKind::BrokenPipe => std::process::exit(0),
+            _ => std::process::exit(1),
+        };
+    });
+}
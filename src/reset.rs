@@ -0,0 +1,117 @@
+use crate::exercise::Exercise;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// The directory the original exercise sources are kept in, next to info.toml,
+// at the same paths as the exercises are listed at in it
+pub const PRISTINE_DIR: &str = "pristine";
+
+// The original sources of the stock exercises, by their path in info.toml,
+// embedded by build.rs
+include!(concat!(env!("OUT_DIR"), "/pristine.rs"));
+// The directory the learner's version of an exercise is backed up to before it is reset
+pub const BACKUP_DIR: &str = ".rustlings-backup";
+
+// What happened to a single exercise when it was reset or restored
+#[derive(Debug, PartialEq)]
+pub enum ResetResult {
+    // The exercise was overwritten, with the previous version backed up if any
+    Reset,
+    // The exercise already had its original contents, so nothing was touched
+    AlreadyPristine,
+    // The learner's version was restored from the backup
+    Restored,
+    // There was nothing to restore the exercise from
+    NoBackup,
+}
+
+// Every exercise pack keeps the original sources in a pristine directory of its own
+fn pristine_path(exercise: &Exercise) -> PathBuf {
+    match &exercise.pack {
+        Some(pack) => pack
+            .dir
+            .join(PRISTINE_DIR)
+            .join(pack.relative(&exercise.path)),
+        None => PathBuf::from(PRISTINE_DIR).join(&exercise.path),
+    }
+}
+
+fn backup_path(exercise: &Exercise) -> PathBuf {
+    match &exercise.pack {
+        Some(pack) => PathBuf::from(BACKUP_DIR)
+            .join(&pack.name)
+            .join(pack.relative(&exercise.path)),
+        None => PathBuf::from(BACKUP_DIR).join(&exercise.path),
+    }
+}
+
+// The original source of the exercise. A copy in the pristine directory
+// wins over the version embedded in the binary, which only stock exercises have.
+fn pristine(exercise: &Exercise) -> io::Result<Vec<u8>> {
+    let pristine_path = pristine_path(exercise);
+    let error = match fs::read(&pristine_path) {
+        Ok(pristine) => return Ok(pristine),
+        Err(e) => e,
+    };
+    let embedded = match &exercise.pack {
+        Some(_) => None,
+        None => {
+            let name = exercise
+                .path
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            EMBEDDED.iter().find(|(path, _)| *path == name)
+        }
+    };
+    match embedded {
+        Some((_, pristine)) => Ok(pristine.to_vec()),
+        None => Err(io::Error::new(
+            error.kind(),
+            format!(
+                "no original version at {}: {}",
+                pristine_path.display(),
+                error
+            ),
+        )),
+    }
+}
+
+// Restore the original source of the exercise, backing up the current
+// version first so that it can be brought back with `undo`
+pub fn reset(exercise: &Exercise) -> io::Result<ResetResult> {
+    let pristine = pristine(exercise)?;
+    let current = match fs::read(&exercise.path) {
+        Ok(current) => Some(current),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+    match current {
+        Some(current) if current == pristine => return Ok(ResetResult::AlreadyPristine),
+        Some(current) => {
+            let backup_path = backup_path(exercise);
+            if let Some(parent) = backup_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(backup_path, current)?;
+        }
+        None => {}
+    }
+    fs::write(&exercise.path, pristine)?;
+    Ok(ResetResult::Reset)
+}
+
+// Bring back the version of the exercise that was backed up by the last `reset`
+pub fn undo(exercise: &Exercise) -> io::Result<ResetResult> {
+    let backup_path = backup_path(exercise);
+    let backup = match fs::read(&backup_path) {
+        Ok(backup) => backup,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(ResetResult::NoBackup),
+        Err(e) => return Err(e),
+    };
+    fs::write(&exercise.path, backup)?;
+    fs::remove_file(backup_path)?;
+    Ok(ResetResult::Restored)
+}
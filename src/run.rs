@@ -1,16 +1,22 @@
-use crate::exercise::{Exercise, Mode};
-use crate::verify::test;
-use indicatif::ProgressBar;
+use crate::exercise::{CompileOptions, Exercise, Mode};
+use crate::progress::Progress;
+use crate::ui;
+use crate::verify::{print_codes, test, warn_run_failure};
 
 // Invoke the rust compiler on the path of the given exercise,
 // and run the ensuing binary.
 // The verbose argument helps determine whether or not to show
 // the output from the test harnesses (if the mode of the exercise is test)
-pub fn run(exercise: &Exercise, verbose: bool) -> Result<(), ()> {
+pub fn run(
+    exercise: &Exercise,
+    progress: &mut Progress,
+    verbose: bool,
+    options: &CompileOptions,
+) -> Result<(), ()> {
     match exercise.mode {
-        Mode::Test => test(exercise, verbose)?,
-        Mode::Compile => compile_and_run(exercise)?,
-        Mode::Clippy => compile_and_run(exercise)?,
+        Mode::Test | Mode::Cargo => test(exercise, progress, verbose, options)?,
+        Mode::Compile => compile_and_run(exercise, options)?,
+        Mode::Clippy => compile_and_run(exercise, options)?,
     }
     Ok(())
 }
@@ -18,14 +24,16 @@ pub fn run(exercise: &Exercise, verbose: bool) -> Result<(), ()> {
 // Invoke the rust compiler on the path of the given exercise
 // and run the ensuing binary.
 // This is strictly for non-test binaries, so output is displayed
-fn compile_and_run(exercise: &Exercise) -> Result<(), ()> {
-    let progress_bar = ProgressBar::new_spinner();
-    progress_bar.set_message(format!("Compiling {}...", exercise).as_str());
-    progress_bar.enable_steady_tick(100);
+fn compile_and_run(exercise: &Exercise, options: &CompileOptions) -> Result<(), ()> {
+    let progress_bar = ui::spinner(&format!("Compiling {}...", exercise));
 
-    let compilation_result = exercise.compile();
+    let compilation_result = exercise.compile(options);
     let compilation = match compilation_result {
         Ok(compilation) => compilation,
+        Err(output) if output.cancelled => {
+            progress_bar.finish_and_clear();
+            return Err(());
+        }
         Err(output) => {
             progress_bar.finish_and_clear();
             warn!(
@@ -33,6 +41,7 @@ fn compile_and_run(exercise: &Exercise) -> Result<(), ()> {
                 exercise
             );
             println!("{}", output.stderr);
+            print_codes(&output);
             return Err(());
         }
     };
@@ -47,11 +56,14 @@ fn compile_and_run(exercise: &Exercise) -> Result<(), ()> {
             success!("Successfully ran {}", exercise);
             Ok(())
         }
+        Err(output) if output.cancelled => Err(()),
         Err(output) => {
-            println!("{}", output.stdout);
-            println!("{}", output.stderr);
+            if !output.unexpected_output {
+                println!("{}", output.stdout);
+                println!("{}", output.stderr);
+            }
 
-            warn!("Ran {} with errors", exercise);
+            warn_run_failure(exercise, &output);
             Err(())
         }
     }
@@ -0,0 +1,150 @@
+use crate::exercise::Exercise;
+use crate::progress::{Progress, Status};
+use std::path::PathBuf;
+
+// Decides which exercises `watch` verifies after a change. The status of
+// every exercise is remembered until it is edited or verified again, as
+// finding it out means reading and hashing the exercise's files.
+pub struct Scheduler<'a> {
+    exercises: &'a [Exercise],
+    // The canonical paths of the exercises, to match the paths of changed files against
+    paths: Vec<Option<PathBuf>>,
+    statuses: Vec<Option<Status>>,
+    // The exercise the learner is working on, the first one that failed
+    current: Option<usize>,
+}
+
+impl<'a> Scheduler<'a> {
+    pub fn new(exercises: &'a [Exercise]) -> Self {
+        Scheduler {
+            exercises,
+            paths: exercises
+                .iter()
+                .map(|e| e.path.canonicalize().ok())
+                .collect(),
+            statuses: vec![None; exercises.len()],
+            current: None,
+        }
+    }
+
+    fn index(&self, exercise: &Exercise) -> Option<usize> {
+        self.exercises
+            .iter()
+            .position(|e| std::ptr::eq(e, exercise))
+    }
+
+    pub fn current(&self) -> Option<&'a Exercise> {
+        self.current.map(|index| &self.exercises[index])
+    }
+
+    pub fn set_current(&mut self, exercise: &Exercise) {
+        self.current = self.index(exercise);
+    }
+
+    // The exercises the changed files belong to. Exercises of packs may be
+    // outside of the current directory, and cargo exercises are edited
+    // anywhere in their crate.
+    pub fn edited(&self, files: &[PathBuf]) -> Vec<&'a Exercise> {
+        let exercises = self.exercises;
+        exercises
+            .iter()
+            .zip(&self.paths)
+            .filter(|(_, path)| {
+                path.as_ref()
+                    .is_some_and(|path| files.iter().any(|file| file.starts_with(path)))
+            })
+            .map(|(exercise, _)| exercise)
+            .collect()
+    }
+
+    // Forget the status of the exercise, once it was edited, verified or skipped
+    pub fn invalidate(&mut self, exercise: &Exercise) {
+        if let Some(index) = self.index(exercise) {
+            self.statuses[index] = None;
+        }
+    }
+
+    fn status(&mut self, index: usize, progress: &Progress) -> Status {
+        let exercises = self.exercises;
+        *self.statuses[index].get_or_insert_with(|| progress.status(&exercises[index]))
+    }
+
+    // The exercises to verify, in order, after the given exercises were
+    // edited: the edited exercises first, followed by the exercises that are
+    // neither done nor skipped, from the current or first edited exercise on.
+    // Exercises before that are done already, and so are not verified again.
+    pub fn queue(&mut self, edited: &[&'a Exercise], progress: &Progress) -> Vec<&'a Exercise> {
+        let exercises = self.exercises;
+        let start = edited
+            .iter()
+            .filter_map(|e| self.index(e))
+            .chain(self.current)
+            .min()
+            .unwrap_or(0);
+        let mut queue = edited.to_vec();
+        for (index, exercise) in exercises.iter().enumerate().skip(start) {
+            let pending = !matches!(self.status(index, progress), Status::Done | Status::Skipped);
+            if pending && !edited.iter().any(|e| std::ptr::eq(*e, exercise)) {
+                queue.push(exercise);
+            }
+        }
+        queue
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::exercise::{Limits, Lints, Mode};
+
+    fn exercise(name: &str, path: &str) -> Exercise {
+        Exercise {
+            name: name.into(),
+            path: PathBuf::from(path),
+            solution: None,
+            mode: Mode::Compile,
+            topic: None,
+            hint: String::new(),
+            hints: Vec::new(),
+            limits: Limits::default(),
+            lints: Lints::default(),
+            expected_output: None,
+            pack: None,
+        }
+    }
+
+    fn names(queue: &[&Exercise]) -> Vec<String> {
+        queue.iter().map(|e| e.name.clone()).collect()
+    }
+
+    #[test]
+    fn test_queue_skips_done_exercises() {
+        let exercises = vec![
+            exercise("finished", "tests/fixture/state/finished_exercise.rs"),
+            exercise("pending", "tests/fixture/state/pending_exercise.rs"),
+            exercise("test", "tests/fixture/state/pending_test_exercise.rs"),
+        ];
+        let mut progress = Progress::load("tests/fixture/state/does_not_exist").unwrap();
+        progress.record_success(&exercises[0]);
+        let mut scheduler = Scheduler::new(&exercises);
+        assert_eq!(
+            names(&scheduler.queue(&[], &progress)),
+            vec!["pending", "test"]
+        );
+
+        scheduler.set_current(&exercises[1]);
+        let edited = scheduler.edited(&[exercises[2].path.canonicalize().unwrap()]);
+        assert_eq!(names(&edited), vec!["test"]);
+        assert_eq!(
+            names(&scheduler.queue(&edited, &progress)),
+            vec!["test", "pending"]
+        );
+
+        let edited = vec![&exercises[0]];
+        scheduler.invalidate(&exercises[0]);
+        assert_eq!(
+            names(&scheduler.queue(&edited, &progress)),
+            vec!["finished", "pending", "test"]
+        );
+    }
+}
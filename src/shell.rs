@@ -0,0 +1,237 @@
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use std::io::{self, IsTerminal};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+// Every command of the watch shell, with what it does for `help`
+pub const COMMANDS: &[(&str, &str)] = &[
+    ("hint", "reveal the next hint of the current exercise"),
+    (
+        "explain",
+        "explain the given error code, or those of the current exercise",
+    ),
+    ("clear", "clear the screen"),
+    ("list", "list the exercises and their status"),
+    ("run", "run the given exercise, or the current one"),
+    (
+        "skip",
+        "skip the current exercise and move on to the next one",
+    ),
+    (
+        "reset",
+        "restore the current exercise to its original state",
+    ),
+    ("progress", "show how many exercises are done"),
+    ("help", "show this list of commands"),
+    ("quit", "stop watching and exit"),
+];
+
+// A command typed into the shell of `watch`
+#[derive(Debug, PartialEq)]
+pub enum ShellCommand {
+    Hint,
+    // Explain the given error code, or the ones the current exercise fails with
+    Explain(Option<String>),
+    Clear,
+    List,
+    // Run the exercise with the given name, or the current one
+    Run(Option<String>),
+    Skip,
+    Reset,
+    Progress,
+    Help,
+    Quit,
+    Unknown(String),
+}
+
+// Parse a line of input, `None` if there is nothing on it
+pub fn parse(input: &str) -> Option<ShellCommand> {
+    let mut words = input.split_whitespace();
+    let command = match words.next()? {
+        "hint" => ShellCommand::Hint,
+        "explain" => ShellCommand::Explain(words.next().map(String::from)),
+        "clear" => ShellCommand::Clear,
+        "list" => ShellCommand::List,
+        "run" => ShellCommand::Run(words.next().map(String::from)),
+        "skip" => ShellCommand::Skip,
+        "reset" => ShellCommand::Reset,
+        "progress" => ShellCommand::Progress,
+        "help" => ShellCommand::Help,
+        "quit" | "exit" => ShellCommand::Quit,
+        _ => ShellCommand::Unknown(input.trim().to_string()),
+    };
+    Some(command)
+}
+
+// Complete the word before `pos`: a command at the start of the line, or
+// the name of an exercise after `run`. Returns where the completed word
+// starts and the candidates for it.
+fn complete(line: &str, pos: usize, names: &[String]) -> (usize, Vec<String>) {
+    let line = &line[..pos];
+    match line.rsplit_once(' ') {
+        None => (
+            0,
+            COMMANDS
+                .iter()
+                .map(|(command, _)| command.to_string())
+                .filter(|command| command.starts_with(line))
+                .collect(),
+        ),
+        Some((command, word)) if command.trim() == "run" => (
+            pos - word.len(),
+            names
+                .iter()
+                .filter(|name| name.starts_with(word))
+                .cloned()
+                .collect(),
+        ),
+        Some(_) => (pos, Vec::new()),
+    }
+}
+
+// Completes the commands and exercise names for the line editor
+struct ShellHelper {
+    names: Arc<RwLock<Vec<String>>>,
+}
+
+impl Completer for ShellHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(complete(line, pos, &self.names.read().unwrap()))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+// Read commands on a thread of their own and send them on, wrapped by `wrap`,
+// until `quit`. Ctrl-C and Ctrl-D quit as well when reading from a terminal.
+// `names` are completed after `run`, and change when the exercises are reloaded.
+pub fn spawn<T: Send + 'static>(
+    names: Arc<RwLock<Vec<String>>>,
+    tx: Sender<T>,
+    wrap: fn(ShellCommand) -> T,
+) {
+    thread::spawn(move || {
+        let mut editor: Editor<ShellHelper, DefaultHistory> = match Editor::new() {
+            Ok(editor) => editor,
+            Err(error) => return println!("error reading commands: {}", error),
+        };
+        editor.set_helper(Some(ShellHelper { names }));
+        loop {
+            let command = match editor.readline("") {
+                Ok(input) => {
+                    let _ = editor.add_history_entry(input.as_str());
+                    match parse(&input) {
+                        Some(command) => command,
+                        None => continue,
+                    }
+                }
+                Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof)
+                    if io::stdin().is_terminal() =>
+                {
+                    ShellCommand::Quit
+                }
+                Err(ReadlineError::Eof) => return,
+                Err(error) => return println!("error reading command: {}", error),
+            };
+            let quit = command == ShellCommand::Quit;
+            if tx.send(wrap(command)).is_err() || quit {
+                return;
+            }
+        }
+    });
+}
+
+// The terminal settings from before the shell started. The line editor
+// switches the terminal to raw mode while it waits for input, so they are
+// put back if watch ends in the middle of reading a command.
+pub struct TerminalMode {
+    #[cfg(target_os = "linux")]
+    saved: Option<libc::termios>,
+}
+
+impl TerminalMode {
+    #[cfg(target_os = "linux")]
+    pub fn save() -> Self {
+        let mut termios = unsafe { std::mem::zeroed::<libc::termios>() };
+        let saved = if unsafe { libc::tcgetattr(libc::STDIN_FILENO, &mut termios) } == 0 {
+            Some(termios)
+        } else {
+            None
+        };
+        TerminalMode { saved }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn save() -> Self {
+        TerminalMode {}
+    }
+}
+
+impl Drop for TerminalMode {
+    fn drop(&mut self) {
+        #[cfg(target_os = "linux")]
+        if let Some(termios) = &self.saved {
+            unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, termios) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("  "), None);
+        assert_eq!(parse("hint"), Some(ShellCommand::Hint));
+        assert_eq!(
+            parse("run variables1"),
+            Some(ShellCommand::Run(Some(String::from("variables1"))))
+        );
+        assert_eq!(parse("run"), Some(ShellCommand::Run(None)));
+        assert_eq!(
+            parse("explain E0382"),
+            Some(ShellCommand::Explain(Some(String::from("E0382"))))
+        );
+        assert_eq!(
+            parse("hnit "),
+            Some(ShellCommand::Unknown(String::from("hnit")))
+        );
+    }
+
+    #[test]
+    fn test_complete() {
+        let names = vec![String::from("variables1"), String::from("functions1")];
+        assert_eq!(
+            complete("r", 1, &names),
+            (0, vec![String::from("run"), String::from("reset")])
+        );
+        assert_eq!(
+            complete("run var", 7, &names),
+            (4, vec![String::from("variables1")])
+        );
+        assert_eq!(complete("hint var", 8, &names), (8, Vec::new()));
+    }
+}
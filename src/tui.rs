@@ -0,0 +1,440 @@
+use crate::config;
+use crate::exercise::{CompileOptions, Exercise};
+use crate::progress::{Progress, Status};
+use crate::reset::{self, ResetResult};
+use crate::verify::{check, OutcomeKind};
+use console::strip_ansi_codes;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Print, PrintStyledContent, Stylize};
+use crossterm::terminal::{
+    self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
+    LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+use std::io::{self, Write};
+use std::process::Command;
+
+const KEYS: &str =
+    "↑/↓ select  enter run  h hint  n next  s skip  x reset  e edit  pgup/pgdn scroll  q quit";
+
+// Puts the terminal into full-screen raw mode, and back once it goes away
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+// The state of the exercise browser
+struct App<'a> {
+    exercises: &'a [Exercise],
+    progress: &'a mut Progress,
+    options: &'a CompileOptions,
+    selected: usize,
+    // The first exercise visible in the sidebar
+    scroll: usize,
+    // What the output pane is showing, and the first of its lines that is visible
+    title: String,
+    output: Vec<String>,
+    output_scroll: usize,
+    // The result of the last action, shown in the status line
+    message: String,
+}
+
+// Browse and work on the exercises in a full-screen terminal interface,
+// until the learner quits
+pub fn tui(
+    exercises: &[Exercise],
+    progress: &mut Progress,
+    options: &CompileOptions,
+) -> io::Result<()> {
+    if exercises.is_empty() {
+        println!("There are no exercises to browse.");
+        return Ok(());
+    }
+    let mut app = App::new(exercises, progress, options);
+
+    let mut screen = Some(Screen::enter()?);
+    loop {
+        app.draw()?;
+        let key = match event::read()? {
+            Event::Key(key) if key.kind != KeyEventKind::Release => key,
+            _ => continue,
+        };
+        app.message.clear();
+        match key {
+            KeyEvent {
+                code: KeyCode::Char('c'),
+                modifiers: KeyModifiers::CONTROL,
+                ..
+            } => break,
+            KeyEvent { code, .. } => match code {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Up | KeyCode::Char('k') => app.select(app.selected.saturating_sub(1)),
+                KeyCode::Down | KeyCode::Char('j') => app.select(app.selected + 1),
+                KeyCode::PageUp => app.output_scroll = app.output_scroll.saturating_sub(10),
+                KeyCode::PageDown => app.output_scroll += 10,
+                KeyCode::Enter | KeyCode::Char('r') => app.run()?,
+                KeyCode::Char('h') => app.hint(),
+                KeyCode::Char('n') => app.next(),
+                KeyCode::Char('s') => app.skip(),
+                KeyCode::Char('x') => app.reset(),
+                KeyCode::Char('e') => {
+                    // The editor needs the terminal to itself
+                    drop(screen.take());
+                    let edited = app.edit();
+                    screen = Some(Screen::enter()?);
+                    match edited {
+                        Ok(()) => app.run()?,
+                        Err(e) => app.message = format!("Failed to start your editor: {}", e),
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    drop(screen);
+    Ok(())
+}
+
+impl<'a> App<'a> {
+    fn new(
+        exercises: &'a [Exercise],
+        progress: &'a mut Progress,
+        options: &'a CompileOptions,
+    ) -> Self {
+        let selected = exercises
+            .iter()
+            .position(|e| progress.is_pending(e))
+            .unwrap_or(0);
+        App {
+            exercises,
+            progress,
+            options,
+            selected,
+            scroll: 0,
+            title: String::from("Welcome"),
+            output: vec![
+                String::from("Select an exercise on the left and press enter to run it."),
+                String::from(
+                    "Press e to open it in your editor, it is run again once you're done.",
+                ),
+            ],
+            output_scroll: 0,
+            message: String::new(),
+        }
+    }
+
+    fn exercise(&self) -> &Exercise {
+        &self.exercises[self.selected]
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index.min(self.exercises.len().saturating_sub(1));
+    }
+
+    fn show(&mut self, title: String, output: Vec<String>) {
+        self.title = title;
+        self.output = output;
+        self.output_scroll = 0;
+    }
+
+    // Check the selected exercise and show what came out of it
+    fn run(&mut self) -> io::Result<()> {
+        let exercises = self.exercises;
+        let exercise = &exercises[self.selected];
+        self.message = format!("Checking {}...", exercise.name);
+        self.draw()?;
+
+        // Running a skipped exercise takes it up again
+        self.progress.set_skipped(exercise, false);
+        let outcome = check(exercise, self.options);
+        if let Some(report) = &outcome.tests {
+            self.progress.record_tests(exercise, report.counts);
+        }
+        if outcome.kind == OutcomeKind::Success {
+            self.progress.record_success(exercise);
+        }
+        self.message = match self.progress.save() {
+            Ok(()) => match self.progress.status(exercise) {
+                Status::Done => format!("{} is done!", exercise.name),
+                _ if outcome.kind == OutcomeKind::Success => format!(
+                    "{} passes, remove the `I AM NOT DONE` comment to move on",
+                    exercise.name
+                ),
+                _ => format!("{}: {}", exercise.name, outcome.kind),
+            },
+            Err(e) => format!("Failed to save your progress: {}", e),
+        };
+
+        let mut output = Vec::new();
+        if let Some(report) = &outcome.tests {
+            output.push(format!("{} tests passing", report.counts));
+            output.push(String::new());
+        }
+        output.extend(output_lines(&outcome.output.stderr));
+        let (lints, codes) = (outcome.output.lints(), outcome.output.codes());
+        if !lints.is_empty() {
+            output.push(format!("Lints that fired: {}", lints.join(", ")));
+        }
+        if !codes.is_empty() {
+            output.push(format!(
+                "Error codes: {} (see `rustlings explain`)",
+                codes.join(", ")
+            ));
+        }
+        output.extend(output_lines(&outcome.output.stdout));
+        self.show(format!("{} ({})", exercise.name, outcome.kind), output);
+        Ok(())
+    }
+
+    // Reveal the next hint of the selected exercise
+    fn hint(&mut self) {
+        let exercises = self.exercises;
+        let exercise = &exercises[self.selected];
+        let levels = exercise.hint_levels();
+        if levels.is_empty() {
+            self.message = format!("There are no hints for {}", exercise.name);
+            return;
+        }
+        let revealed = self.progress.reveal_hint(exercise);
+        if let Err(e) = self.progress.save() {
+            self.message = format!("Failed to save your progress: {}", e);
+        }
+        let mut output = Vec::new();
+        for hint in levels.iter().take(revealed) {
+            output.extend(hint.trim().lines().map(String::from));
+            output.push(String::new());
+        }
+        self.show(
+            format!("{}: hint {}/{}", exercise.name, revealed, levels.len()),
+            output,
+        );
+    }
+
+    // Select the first exercise that is neither done nor skipped
+    fn next(&mut self) {
+        match self
+            .exercises
+            .iter()
+            .position(|e| self.progress.is_pending(e))
+        {
+            Some(index) => self.select(index),
+            None => self.message = String::from("You have done all the exercises!"),
+        }
+    }
+
+    // Skip the selected exercise, as `skip` does in watch, and select the
+    // next one that is neither done nor skipped
+    fn skip(&mut self) {
+        let exercises = self.exercises;
+        let exercise = &exercises[self.selected];
+        self.progress.set_skipped(exercise, true);
+        if let Err(e) = self.progress.save() {
+            self.message = format!("Failed to save your progress: {}", e);
+            return;
+        }
+        self.next();
+        if self.message.is_empty() {
+            self.message = format!("Skipped {}, run it to get back to it", exercise.name);
+        }
+    }
+
+    fn reset(&mut self) {
+        let exercise = self.exercise();
+        self.message = match reset::reset(exercise) {
+            Ok(ResetResult::AlreadyPristine) => {
+                format!("{} is already in its original state", exercise.name)
+            }
+            Ok(_) => format!(
+                "Reset {}, `rustlings reset --undo {}` brings your version back",
+                exercise.name, exercise.name
+            ),
+            Err(e) => format!("Failed to reset {}: {}", exercise.name, e),
+        };
+    }
+
+    // Open the selected exercise in the learner's editor and wait for it to close
+    fn edit(&self) -> io::Result<()> {
+        let mut words = config::get().editor.split_whitespace();
+        let program = words.next().unwrap_or("vi");
+        Command::new(program)
+            .args(words)
+            .arg(&self.exercise().path)
+            .status()?;
+        Ok(())
+    }
+
+    fn draw(&mut self) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let (columns, rows) = (columns as usize, rows as usize);
+        let body = rows.saturating_sub(2);
+        let sidebar = self
+            .exercises
+            .iter()
+            .map(|e| e.name.chars().count() + 11)
+            .max()
+            .unwrap_or(0)
+            .min(columns / 3);
+        let pane = columns.saturating_sub(sidebar + 1);
+
+        // Keep the selected exercise in view
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if body > 0 && self.selected >= self.scroll + body {
+            self.scroll = self.selected + 1 - body;
+        }
+        self.output_scroll = self
+            .output_scroll
+            .min(self.output.len().saturating_sub(body.saturating_sub(1)));
+
+        let mut stdout = io::stdout();
+        let done = self
+            .exercises
+            .iter()
+            .filter(|e| self.progress.is_done(e))
+            .count();
+        let header = format!(
+            " rustlings: {} / {} exercises done",
+            done,
+            self.exercises.len()
+        );
+        queue!(
+            stdout,
+            MoveTo(0, 0),
+            PrintStyledContent(fit(&header, columns).reverse())
+        )?;
+
+        for row in 0..body {
+            queue!(stdout, MoveTo(0, row as u16 + 1))?;
+            match self.exercises.get(self.scroll + row) {
+                Some(exercise) => {
+                    let status = self.progress.status(exercise);
+                    let line = fit(&format!(" {:<7} {}", status, exercise.name), sidebar);
+                    let line = match status {
+                        Status::Done => line.green(),
+                        Status::Stale => line.yellow(),
+                        Status::Pending => line.red(),
+                        Status::Skipped => line.dark_grey(),
+                    };
+                    if self.scroll + row == self.selected {
+                        queue!(stdout, PrintStyledContent(line.reverse()))?;
+                    } else {
+                        queue!(stdout, PrintStyledContent(line))?;
+                    }
+                }
+                None => queue!(stdout, Print(fit("", sidebar)))?,
+            }
+            queue!(stdout, Print("│"))?;
+            let line = match row {
+                0 => fit(&format!(" {}", self.title), pane).bold(),
+                _ => match self.output.get(self.output_scroll + row - 1) {
+                    Some(line) => fit(&format!(" {}", line), pane).stylize(),
+                    None => fit("", pane).stylize(),
+                },
+            };
+            queue!(stdout, PrintStyledContent(line))?;
+        }
+
+        let status = if self.message.is_empty() {
+            KEYS
+        } else {
+            &self.message
+        };
+        queue!(
+            stdout,
+            MoveTo(0, rows.saturating_sub(1) as u16),
+            PrintStyledContent(fit(status, columns).dim()),
+            Clear(ClearType::UntilNewLine)
+        )?;
+        stdout.flush()
+    }
+}
+
+// The lines of an exercise's output, without the colors and tabs that
+// would mess up the layout
+fn output_lines(output: &str) -> Vec<String> {
+    strip_ansi_codes(output)
+        .lines()
+        .map(|line| line.replace('\t', "    "))
+        .collect()
+}
+
+// Cut off or pad the text to exactly `width` characters
+fn fit(text: &str, width: usize) -> String {
+    format!("{:<width$.width$}", text, width = width)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validate;
+    use std::fs;
+
+    #[test]
+    fn test_skip() {
+        let path = "target/test-tui-progress";
+        let _ = fs::remove_file(path);
+        let mut progress = Progress::load(path).unwrap();
+        let exercises = validate::parse(
+            r#"
+[[exercises]]
+name = "first"
+path = "tests/fixture/state/pending_exercise.rs"
+mode = "compile"
+
+[[exercises]]
+name = "second"
+path = "tests/fixture/state/pending_test_exercise.rs"
+mode = "test"
+"#,
+        )
+        .unwrap();
+        let options = CompileOptions::default();
+        let mut app = App::new(&exercises, &mut progress, &options);
+        app.skip();
+        assert_eq!(app.selected, 1);
+        assert_eq!(app.message, "Skipped first, run it to get back to it");
+        assert_eq!(app.progress.status(&exercises[0]), Status::Skipped);
+        assert_eq!(
+            Progress::load(path).unwrap().status(&exercises[0]),
+            Status::Skipped
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_select_without_exercises() {
+        let mut progress = Progress::load("target/test-tui-no-progress").unwrap();
+        let options = CompileOptions::default();
+        let mut app = App::new(&[], &mut progress, &options);
+        app.select(3);
+        assert_eq!(app.selected, 0);
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(fit("exercise", 4), "exer");
+        assert_eq!(fit("ok", 4), "ok  ");
+        assert_eq!(fit("✓ ok", 3), "✓ o");
+    }
+
+    #[test]
+    fn test_output_lines() {
+        assert_eq!(
+            output_lines("\x1b[1m\x1b[91merror\x1b[0m: oops\n\tat line 1\n"),
+            vec!["error: oops", "    at line 1"]
+        );
+    }
+}
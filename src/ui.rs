@@ -1,9 +1,11 @@
+use crate::config;
+use indicatif::{ProgressBar, ProgressStyle};
+
 macro_rules! warn {
     ($fmt:literal, $ex:expr) => {{
         use console::{style, Emoji};
-        use std::env;
         let formatstr = format!($fmt, $ex);
-        if env::var("NO_EMOJI").is_ok() {
+        if !crate::config::get().emoji {
             println!("{} {}", style("!").red(), style(formatstr).red());
         } else {
             println!(
@@ -18,9 +20,8 @@ macro_rules! warn {
 macro_rules! success {
     ($fmt:literal, $ex:expr) => {{
         use console::{style, Emoji};
-        use std::env;
         let formatstr = format!($fmt, $ex);
-        if env::var("NO_EMOJI").is_ok() {
+        if !crate::config::get().emoji {
             println!("{} {}", style("✓").green(), style(formatstr).green());
         } else {
             println!(
@@ -31,3 +32,18 @@ macro_rules! success {
         }
     }};
 }
+
+// A spinner with the message, which is only drawn to a terminal and only
+// colored when the output is
+pub fn spinner(message: &str) -> ProgressBar {
+    let template = if config::get().color.enabled() {
+        "{spinner:.green} {msg}"
+    } else {
+        "{spinner} {msg}"
+    };
+    let progress_bar = ProgressBar::new_spinner();
+    progress_bar.set_style(ProgressStyle::default_spinner().template(template));
+    progress_bar.set_message(message);
+    progress_bar.enable_steady_tick(100);
+    progress_bar
+}
@@ -0,0 +1,463 @@
+use crate::exercise::{Exercise, ExpectedOutput, Lints, Mode};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The file exercises are listed in
+pub const INFO_FILE: &str = "info.toml";
+// The directory every exercise is expected to live in
+pub const EXERCISES_DIR: &str = "exercises";
+const MODES: &[&str] = &["compile", "test", "clippy", "cargo"];
+// Every key an exercise in info.toml may have, see `Exercise`
+const KEYS: &[&str] = &[
+    "name",
+    "path",
+    "solution",
+    "mode",
+    "topic",
+    "hint",
+    "hints",
+    "timeout",
+    "memory_limit",
+    "cpu_limit",
+    "expected_output",
+    "lints",
+];
+
+// A problem found in info.toml, or in the exercises it lists
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    // The file the problem is in
+    pub file: PathBuf,
+    // The 1-based line and column the problem is at, if it is about a specific place
+    pub position: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn at(position: Option<(usize, usize)>, message: String) -> Self {
+        Diagnostic {
+            file: PathBuf::from(INFO_FILE),
+            position,
+            message,
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some((line, column)) = self.position {
+            write!(f, ":{}:{}", line, column)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+// Where an `[[exercises]]` table and its keys are in info.toml.
+// toml doesn't keep track of positions, so they are found by scanning the source.
+#[derive(Clone, Debug, Default)]
+struct Table {
+    line: usize,
+    keys: HashMap<String, (usize, usize)>,
+}
+
+impl Table {
+    // The position of the key's value, or of the table header if the key is missing
+    fn position(&self, key: &str) -> Option<(usize, usize)> {
+        Some(self.keys.get(key).copied().unwrap_or((self.line, 1)))
+    }
+}
+
+fn scan(source: &str) -> Vec<Table> {
+    let mut tables: Vec<Table> = Vec::new();
+    let mut in_multiline_string = false;
+    for (i, line) in source.lines().enumerate() {
+        let delimiters = line.matches("\"\"\"").count() + line.matches("'''").count();
+        let starts_in_string = in_multiline_string;
+        if delimiters % 2 == 1 {
+            in_multiline_string = !in_multiline_string;
+        }
+        if starts_in_string {
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("[[exercises]]") {
+            tables.push(Table {
+                line: i + 1,
+                ..Table::default()
+            });
+            continue;
+        }
+        let table = match tables.last_mut() {
+            Some(table) => table,
+            None => continue,
+        };
+        if let Some((key, _)) = trimmed.split_once('=') {
+            let key = key.trim();
+            if !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                let value = line.find('=').unwrap() + 1;
+                let column = value + line[value..].len() - line[value..].trim_start().len() + 1;
+                table.keys.insert(key.to_string(), (i + 1, column));
+            }
+        }
+    }
+    tables
+}
+
+// Parse the exercises listed in info.toml. Everything that keeps an exercise
+// from being loaded at all, like syntax errors or unknown modes, is reported.
+pub fn parse(source: &str) -> Result<Vec<Exercise>, Vec<Diagnostic>> {
+    let value = source.parse::<toml::Value>().map_err(|e| {
+        let mut message = e.to_string();
+        if let Some(at) = message.rfind(" at line ") {
+            message.truncate(at);
+        }
+        vec![Diagnostic::at(
+            e.line_col().map(|(line, column)| (line + 1, column + 1)),
+            message,
+        )]
+    })?;
+    let list = match value.get("exercises").and_then(|e| e.as_array()) {
+        Some(list) => list,
+        None => {
+            return Err(vec![Diagnostic::at(
+                None,
+                String::from("expected a list of [[exercises]]"),
+            )])
+        }
+    };
+
+    let tables = scan(source);
+    let mut exercises = Vec::new();
+    let mut diagnostics = Vec::new();
+    for (i, value) in list.iter().enumerate() {
+        let table = tables.get(i).cloned().unwrap_or_default();
+        let mut valid = true;
+        for key in &["name", "path", "mode"] {
+            if value.get(key).is_none() {
+                diagnostics.push(Diagnostic::at(
+                    Some((table.line, 1)),
+                    format!("exercise is missing `{}`", key),
+                ));
+                valid = false;
+            }
+        }
+        if let Some(mode) = value.get("mode").and_then(|m| m.as_str()) {
+            if !MODES.contains(&mode) {
+                diagnostics.push(Diagnostic::at(
+                    table.position("mode"),
+                    format!(
+                        "unknown mode `{}`, expected one of `compile`, `test`, `clippy` or `cargo`",
+                        mode
+                    ),
+                ));
+                valid = false;
+            }
+        }
+        if !valid {
+            continue;
+        }
+        match value.clone().try_into::<Exercise>() {
+            Ok(mut exercise) => {
+                // An exercise whose output can never match could never pass
+                if let Some(ExpectedOutput::Regex(re)) = &exercise.expected_output {
+                    if let Err(e) = Regex::new(re) {
+                        diagnostics.push(Diagnostic::at(
+                            table.position("expected_output"),
+                            format!("invalid regex in `expected_output`: {}", regex_error(&e)),
+                        ));
+                        continue;
+                    }
+                }
+                if exercise.topic.is_none() {
+                    exercise.topic = default_topic(&exercise.path);
+                }
+                exercises.push(exercise)
+            }
+            Err(e) => diagnostics.push(Diagnostic::at(Some((table.line, 1)), e.to_string())),
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(exercises)
+    } else {
+        Err(diagnostics)
+    }
+}
+
+// What is wrong with a regex, without the pattern and the caret pointing
+// into it that the error spells out on lines of their own
+fn regex_error(e: &regex::Error) -> String {
+    let message = e.to_string();
+    let last = message.lines().last().unwrap_or_default();
+    last.strip_prefix("error: ").unwrap_or(last).to_string()
+}
+
+// The topic of an exercise without one in info.toml is the directory it is in,
+// e.g. `variables` for `exercises/variables/variables1.rs`
+fn default_topic(path: &Path) -> Option<String> {
+    path.parent()
+        .filter(|dir| !dir.ends_with(EXERCISES_DIR))
+        .and_then(|dir| dir.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+}
+
+// Look for mistakes in the exercises of info.toml that don't keep them from
+// being loaded, but that authors want to know about before committing.
+// Every `.rs` file in `exercises_dir` is expected to be one of the exercises.
+pub fn check(source: &str, exercises: &[Exercise], exercises_dir: &Path) -> Vec<Diagnostic> {
+    let tables = scan(source);
+    let mut diagnostics = Vec::new();
+    let mut names: HashMap<&str, usize> = HashMap::new();
+
+    for (exercise, table) in exercises.iter().zip(&tables) {
+        let mut unknown: Vec<&String> = table
+            .keys
+            .keys()
+            .filter(|k| !KEYS.contains(&k.as_str()))
+            .collect();
+        unknown.sort();
+        for key in unknown {
+            diagnostics.push(Diagnostic::at(
+                table.keys.get(key).copied(),
+                format!("unknown key `{}`", key),
+            ));
+        }
+        if let Some(first) = names.insert(&exercise.name, table.line) {
+            diagnostics.push(Diagnostic::at(
+                table.position("name"),
+                format!(
+                    "duplicate name `{}`, it is already used by the exercise at line {}",
+                    exercise.name, first
+                ),
+            ));
+        }
+        if exercise.mode == Mode::Cargo {
+            if !exercise.path.join("Cargo.toml").is_file() {
+                diagnostics.push(Diagnostic::at(
+                    table.position("path"),
+                    format!(
+                        "`{}` is not a crate with a Cargo.toml",
+                        exercise.path.display()
+                    ),
+                ));
+            }
+        } else if !exercise.path.is_file() {
+            diagnostics.push(Diagnostic::at(
+                table.position("path"),
+                format!("`{}` does not exist", exercise.path.display()),
+            ));
+        }
+        if let Some(solution) = &exercise.solution {
+            if !solution.is_file() {
+                diagnostics.push(Diagnostic::at(
+                    table.position("solution"),
+                    format!("`{}` does not exist", solution.display()),
+                ));
+            }
+        }
+        if exercise.lints != Lints::default() && exercise.mode != Mode::Clippy {
+            diagnostics.push(Diagnostic::at(
+                table.position("lints"),
+                format!(
+                    "`lints` only apply to clippy exercises, `{}` is not one",
+                    exercise.name
+                ),
+            ));
+        }
+        if let Some(config) = &exercise.lints.config {
+            if !config.is_file() {
+                diagnostics.push(Diagnostic::at(
+                    table.position("lints"),
+                    format!("`{}` does not exist", config.display()),
+                ));
+            }
+        }
+        if exercise.hint_levels().is_empty() {
+            diagnostics.push(Diagnostic::at(
+                table.position(if table.keys.contains_key("hints") {
+                    "hints"
+                } else {
+                    "hint"
+                }),
+                format!("`{}` has no hint", exercise.name),
+            ));
+        } else if exercise.hints.iter().any(|h| h.trim().is_empty()) {
+            diagnostics.push(Diagnostic::at(
+                table.position("hints"),
+                format!("`{}` has an empty hint", exercise.name),
+            ));
+        }
+    }
+
+    let mut orphans = Vec::new();
+    find_orphans(exercises_dir, exercises, &mut orphans);
+    orphans.sort();
+    diagnostics.extend(orphans.into_iter().map(|file| Diagnostic {
+        file,
+        position: None,
+        message: format!("not listed in {}", INFO_FILE),
+    }));
+    diagnostics
+}
+
+// Collect the `.rs` files under `dir` that none of the exercises point at.
+// The files of cargo exercises are part of the exercise's crate.
+fn find_orphans(dir: &Path, exercises: &[Exercise], orphans: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            find_orphans(&path, exercises, orphans);
+        } else if path.extension().is_some_and(|e| e == "rs")
+            && !exercises
+                .iter()
+                .any(|e| e.path == path || (e.mode == Mode::Cargo && path.starts_with(&e.path)))
+        {
+            orphans.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_syntax_error_position() {
+        let errors = parse("[[exercises]]\nname = \"a\nmode = \"compile\"\n").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].position.map(|(line, _)| line), Some(2));
+    }
+
+    #[test]
+    fn test_unknown_mode() {
+        let source = "[[exercises]]\nname = \"a\"\npath = \"a.rs\"\nmode = \"compil\"\n";
+        let errors = parse(source).unwrap_err();
+        assert_eq!(errors[0].position, Some((4, 8)));
+        assert_eq!(
+            errors[0].to_string(),
+            "info.toml:4:8: unknown mode `compil`, expected one of `compile`, `test`, `clippy` or `cargo`"
+        );
+    }
+
+    #[test]
+    fn test_invalid_expected_output_regex() {
+        let source = "[[exercises]]\nname = \"a\"\npath = \"a.rs\"\nmode = \"compile\"\nexpected_output = { regex = '(waiting' }\n";
+        let errors = parse(source).unwrap_err();
+        assert_eq!(
+            errors[0].to_string(),
+            "info.toml:5:19: invalid regex in `expected_output`: unclosed group"
+        );
+    }
+
+    #[test]
+    fn test_check_finds_mistakes() {
+        let source = r#"
+[[exercises]]
+name = "a"
+path = "tests/fixture/state/finished_exercise.rs"
+mode = "compile"
+hint = """
+a hint with a = sign
+"""
+
+[[exercises]]
+name = "a"
+path = "does/not/exist.rs"
+mode = "compile"
+hnit = "typo"
+"#;
+        let exercises = parse(source).unwrap();
+        let messages: Vec<String> = check(source, &exercises, Path::new("tests/fixture/state"))
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "info.toml:14:8: unknown key `hnit`",
+                "info.toml:11:8: duplicate name `a`, it is already used by the exercise at line 2",
+                "info.toml:12:8: `does/not/exist.rs` does not exist",
+                "info.toml:10:1: `a` has no hint",
+                "tests/fixture/state/pending_exercise.rs: not listed in info.toml",
+                "tests/fixture/state/pending_test_exercise.rs: not listed in info.toml",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_topic_defaults_to_directory() {
+        let source = r#"
+[[exercises]]
+name = "a"
+path = "exercises/variables/a.rs"
+mode = "compile"
+
+[[exercises]]
+name = "b"
+path = "exercises/b.rs"
+mode = "compile"
+
+[[exercises]]
+name = "c"
+path = "exercises/variables/c.rs"
+mode = "compile"
+topic = "shadowing"
+"#;
+        let topics: Vec<Option<String>> = parse(source)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.topic)
+            .collect();
+        assert_eq!(
+            topics,
+            vec![
+                Some(String::from("variables")),
+                None,
+                Some(String::from("shadowing"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lints_are_checked() {
+        let source = r#"
+[[exercises]]
+name = "a"
+path = "tests/fixture/lints/needlessReturn.rs"
+mode = "compile"
+lints = { allow = ["clippy::needless_return"] }
+hint = "a"
+
+[[exercises]]
+name = "b"
+path = "tests/fixture/lints/manyArguments.rs"
+mode = "clippy"
+lints = { config = "does/not/clippy.toml" }
+hint = "b"
+"#;
+        let exercises = parse(source).unwrap();
+        let messages: Vec<String> = check(source, &exercises, Path::new("tests/fixture/lints"))
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "info.toml:6:9: `lints` only apply to clippy exercises, `a` is not one",
+                "info.toml:13:9: `does/not/clippy.toml` does not exist",
+            ]
+        );
+    }
+}
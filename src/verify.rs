@@ -1,23 +1,47 @@
-use crate::exercise::{CompiledExercise, Exercise, Mode, State};
+use crate::config;
+use crate::diff;
+use crate::exercise::{
+    CompileOptions, CompiledExercise, Exercise, ExerciseOutput, ExpectedOutput, Mode, State,
+};
+use crate::harness::{self, TestReport};
+use crate::progress::Progress;
+use crate::report::{Record, Reporter};
+use crate::ui;
 use console::style;
 use indicatif::ProgressBar;
-use std::env;
+use serde::Serialize;
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::{Duration, Instant};
 
 // Verify that the provided container of Exercise objects
 // can be compiled and run without any failures.
 // Any such failures will be reported to the end user.
+// Every exercise that passes is recorded in the given progress store.
+// Skipped exercises are verified like any other, as skipping only moves
+// them out of the way in `watch`.
 // If the Exercise being verified is a test, the verbose boolean
 // determines whether or not the test harness outputs are displayed.
 pub fn verify<'a>(
     start_at: impl IntoIterator<Item = &'a Exercise>,
+    progress: &mut Progress,
     verbose: bool,
+    options: &CompileOptions,
 ) -> Result<(), &'a Exercise> {
     for exercise in start_at {
         let compile_result = match exercise.mode {
-            Mode::Test => compile_and_test(exercise, RunMode::Interactive, verbose),
-            Mode::Compile => compile_and_run_interactively(exercise),
-            Mode::Clippy => compile_only(exercise),
+            Mode::Test | Mode::Cargo => {
+                compile_and_test(exercise, RunMode::Interactive, progress, verbose, options)
+            }
+            Mode::Compile => compile_and_run_interactively(exercise, options),
+            Mode::Clippy => compile_only(exercise, options),
         };
+        if compile_result.is_ok() {
+            progress.record_success(exercise);
+            save(progress);
+        }
         if !compile_result.unwrap_or(false) {
             return Err(exercise);
         }
@@ -25,24 +49,250 @@ pub fn verify<'a>(
     Ok(())
 }
 
+// Same as `verify`, but nothing is printed along the way.
+// Every exercise that was checked ends up in the given reporter instead.
+pub fn verify_with_report<'a>(
+    start_at: impl IntoIterator<Item = &'a Exercise>,
+    progress: &mut Progress,
+    reporter: &mut Reporter<'a>,
+    options: &CompileOptions,
+) -> Result<(), &'a Exercise> {
+    for exercise in start_at {
+        let outcome = check(exercise, options);
+        let passed = outcome.kind == OutcomeKind::Success;
+        if let Some(report) = &outcome.tests {
+            progress.record_tests(exercise, report.counts);
+        }
+        if passed {
+            progress.record_success(exercise);
+        }
+        // There is nowhere to report this to without breaking the output format
+        let _ = progress.save();
+        if passed {
+            reporter.count("passed");
+        } else {
+            reporter.count("failed");
+        }
+        let record = Record::new(exercise, progress).with_outcome(outcome);
+        let pending = record.state != State::Done;
+        reporter.exercise(record);
+        if !passed || pending {
+            return Err(exercise);
+        }
+    }
+    Ok(())
+}
+
+// Check every exercise, regardless of failures along the way and of the
+// `I AM NOT DONE` marker, on a pool of `jobs` worker threads.
+// Exercises that pass are recorded in the progress store, and a table of
+// all results is printed (or handed to the reporter for machine-readable
+// formats). Returns the number of exercises that failed.
+pub fn verify_all<'a>(
+    exercises: &'a [Exercise],
+    progress: &mut Progress,
+    jobs: usize,
+    reporter: &mut Reporter<'a>,
+    options: &CompileOptions,
+) -> usize {
+    let progress_bar = if reporter.is_text() {
+        ProgressBar::new(exercises.len() as u64)
+    } else {
+        ProgressBar::hidden()
+    };
+    progress_bar.set_message("Checking exercises...");
+
+    let outcomes = check_all(exercises, jobs, options, || progress_bar.inc(1));
+    progress_bar.finish_and_clear();
+
+    if reporter.is_text() {
+        println!(
+            "{:<17}\t{:<7}\t{:<13}\t{:>8}",
+            "Name", "Mode", "Result", "Time"
+        );
+    }
+    let mut failures = 0;
+    for (exercise, outcome) in exercises.iter().zip(outcomes) {
+        if let Some(report) = &outcome.tests {
+            progress.record_tests(exercise, report.counts);
+        }
+        if outcome.kind == OutcomeKind::Success {
+            progress.record_success(exercise);
+            reporter.count("passed");
+        } else {
+            failures += 1;
+            reporter.count("failed");
+        }
+        if reporter.is_text() {
+            let result = format!("{:<13}", outcome.kind);
+            let result = if outcome.kind == OutcomeKind::Success {
+                style(result).green()
+            } else {
+                style(result).red()
+            };
+            println!(
+                "{:<17}\t{:<7}\t{}\t{:>7.2}s",
+                exercise.name,
+                format!("{:?}", exercise.mode).to_lowercase(),
+                result,
+                outcome.duration.as_secs_f32()
+            );
+        }
+        reporter.exercise(Record::new(exercise, progress).with_outcome(outcome));
+    }
+    if let Err(e) = progress.save() {
+        if reporter.is_text() {
+            warn!("Failed to save your progress: {}", e);
+        }
+    }
+
+    if reporter.is_text() {
+        println!();
+        let summary = format!(
+            "{} / {} exercises pass",
+            exercises.len() - failures,
+            exercises.len()
+        );
+        if failures == 0 {
+            success!("{}", summary);
+        } else {
+            warn!("{}", summary);
+        }
+    }
+    failures
+}
+
+// Check the given exercises concurrently on `jobs` threads and return
+// their outcomes in the same order. `on_done` is called once per
+// finished exercise.
+fn check_all(
+    exercises: &[Exercise],
+    jobs: usize,
+    options: &CompileOptions,
+    on_done: impl Fn(),
+) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = channel();
+    let mut outcomes: Vec<Option<Outcome>> = exercises.iter().map(|_| None).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1).min(exercises.len()) {
+            let tx = tx.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                match exercises.get(index) {
+                    Some(exercise) => {
+                        if tx.send((index, check(exercise, options))).is_err() {
+                            return;
+                        }
+                    }
+                    None => return,
+                }
+            });
+        }
+        drop(tx);
+        for (index, outcome) in rx {
+            outcomes[index] = Some(outcome);
+            on_done();
+        }
+    });
+
+    outcomes
+        .into_iter()
+        .map(|outcome| outcome.expect("Every exercise should have been checked"))
+        .collect()
+}
+
+// How checking an exercise ended
+#[derive(Serialize, Copy, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutcomeKind {
+    Success,
+    CompileError,
+    RuntimeError,
+    TimedOut,
+    UnexpectedOutput,
+}
+
+impl Display for OutcomeKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let kind = match self {
+            OutcomeKind::Success => "ok",
+            OutcomeKind::CompileError => "compile error",
+            OutcomeKind::RuntimeError => "runtime error",
+            OutcomeKind::TimedOut => "timed out",
+            OutcomeKind::UnexpectedOutput => "wrong output",
+        };
+        f.pad(kind)
+    }
+}
+
+// The result of checking an exercise without any user interaction
+pub struct Outcome {
+    pub kind: OutcomeKind,
+    // The compiler's output if the compilation failed, the program's otherwise
+    pub output: ExerciseOutput,
+    // The parsed results of a test harness that was run
+    pub tests: Option<TestReport>,
+    pub duration: Duration,
+}
+
+// Compile the given Exercise and, unless it is a clippy exercise,
+// run the resulting binary, without reporting anything to the user
+pub fn check(exercise: &Exercise, options: &CompileOptions) -> Outcome {
+    let started = Instant::now();
+    let (kind, output) = match exercise.compile(options) {
+        Err(output) => (OutcomeKind::CompileError, output),
+        Ok(_) if exercise.mode == Mode::Clippy => (OutcomeKind::Success, ExerciseOutput::default()),
+        Ok(compilation) => match compilation.run() {
+            Ok(output) => (OutcomeKind::Success, output),
+            Err(output) if output.timed_out => (OutcomeKind::TimedOut, output),
+            Err(output) if output.unexpected_output => (OutcomeKind::UnexpectedOutput, output),
+            Err(output) => (OutcomeKind::RuntimeError, output),
+        },
+    };
+    let tests = match (exercise.mode, kind) {
+        (Mode::Test | Mode::Cargo, OutcomeKind::Success)
+        | (Mode::Test | Mode::Cargo, OutcomeKind::RuntimeError)
+        | (Mode::Test | Mode::Cargo, OutcomeKind::TimedOut) => Some(harness::parse(&output.stdout)),
+        _ => None,
+    };
+    Outcome {
+        kind,
+        output,
+        tests,
+        duration: started.elapsed(),
+    }
+}
+
 enum RunMode {
     Interactive,
     NonInteractive,
 }
 
 // Compile and run the resulting test harness of the given Exercise
-pub fn test(exercise: &Exercise, verbose: bool) -> Result<(), ()> {
-    compile_and_test(exercise, RunMode::NonInteractive, verbose)?;
+pub fn test(
+    exercise: &Exercise,
+    progress: &mut Progress,
+    verbose: bool,
+    options: &CompileOptions,
+) -> Result<(), ()> {
+    compile_and_test(
+        exercise,
+        RunMode::NonInteractive,
+        progress,
+        verbose,
+        options,
+    )?;
     Ok(())
 }
 
 // Invoke the rust compiler without running the resulting binary
-fn compile_only(exercise: &Exercise) -> Result<bool, ()> {
-    let progress_bar = ProgressBar::new_spinner();
-    progress_bar.set_message(format!("Compiling {}...", exercise).as_str());
-    progress_bar.enable_steady_tick(100);
+fn compile_only(exercise: &Exercise, options: &CompileOptions) -> Result<bool, ()> {
+    let progress_bar = ui::spinner(&format!("Compiling {}...", exercise));
 
-    let _ = compile(exercise, &progress_bar)?;
+    let _ = compile(exercise, options, &progress_bar)?;
     progress_bar.finish_and_clear();
 
     success!("Successfully compiled {}!", exercise);
@@ -50,12 +300,13 @@ fn compile_only(exercise: &Exercise) -> Result<bool, ()> {
 }
 
 // Compile the given Exercise and run the resulting binary in an interactive mode
-fn compile_and_run_interactively(exercise: &Exercise) -> Result<bool, ()> {
-    let progress_bar = ProgressBar::new_spinner();
-    progress_bar.set_message(format!("Compiling {}...", exercise).as_str());
-    progress_bar.enable_steady_tick(100);
+fn compile_and_run_interactively(
+    exercise: &Exercise,
+    options: &CompileOptions,
+) -> Result<bool, ()> {
+    let progress_bar = ui::spinner(&format!("Compiling {}...", exercise));
 
-    let compilation = compile(exercise, &progress_bar)?;
+    let compilation = compile(exercise, options, &progress_bar)?;
 
     progress_bar.set_message(format!("Running {}...", exercise).as_str());
     let result = compilation.run();
@@ -63,10 +314,13 @@ fn compile_and_run_interactively(exercise: &Exercise) -> Result<bool, ()> {
 
     let output = match result {
         Ok(output) => output,
+        Err(output) if output.cancelled => return Err(()),
         Err(output) => {
-            warn!("Ran {} with errors", exercise);
-            println!("{}", output.stdout);
-            println!("{}", output.stderr);
+            warn_run_failure(exercise, &output);
+            if !output.unexpected_output {
+                println!("{}", output.stdout);
+                println!("{}", output.stderr);
+            }
             return Err(());
         }
     };
@@ -77,15 +331,29 @@ fn compile_and_run_interactively(exercise: &Exercise) -> Result<bool, ()> {
 }
 
 // Compile the given Exercise as a test harness and display
-// the output if verbose is set to true
-fn compile_and_test(exercise: &Exercise, run_mode: RunMode, verbose: bool) -> Result<bool, ()> {
-    let progress_bar = ProgressBar::new_spinner();
-    progress_bar.set_message(format!("Testing {}...", exercise).as_str());
-    progress_bar.enable_steady_tick(100);
+// the output if verbose is set to true. Otherwise only the failing
+// tests are shown. The test results are recorded in the progress store.
+fn compile_and_test(
+    exercise: &Exercise,
+    run_mode: RunMode,
+    progress: &mut Progress,
+    verbose: bool,
+    options: &CompileOptions,
+) -> Result<bool, ()> {
+    let progress_bar = ui::spinner(&format!("Testing {}...", exercise));
 
-    let compilation = compile(exercise, &progress_bar)?;
+    let compilation = compile(exercise, options, &progress_bar)?;
     let result = compilation.run();
     progress_bar.finish_and_clear();
+    if result.as_ref().is_err_and(|output| output.cancelled) {
+        return Err(());
+    }
+
+    let report = match &result {
+        Ok(output) | Err(output) => harness::parse(&output.stdout),
+    };
+    progress.record_tests(exercise, report.counts);
+    save(progress);
 
     match result {
         Ok(output) => {
@@ -100,26 +368,73 @@ fn compile_and_test(exercise: &Exercise, run_mode: RunMode, verbose: bool) -> Re
             }
         }
         Err(output) => {
-            warn!(
-                "Testing of {} failed! Please try again. Here's the output:",
-                exercise
-            );
-            println!("{}", output.stdout);
+            warn_if_timed_out(exercise, &output);
+            if verbose || report.counts.failed == 0 {
+                warn!(
+                    "Testing of {} failed! Please try again. Here's the output:",
+                    exercise
+                );
+                println!("{}", output.stdout);
+            } else {
+                warn!(
+                    "{}",
+                    format!(
+                        "Testing of {} failed, {} tests passing! Please try again.",
+                        exercise, report.counts
+                    )
+                );
+                print_test_failures(&report);
+            }
             Err(())
         }
     }
 }
 
+// Show the name, panic message and assertion values of every failed test
+fn print_test_failures(report: &TestReport) {
+    for failure in report.failures() {
+        println!();
+        println!(
+            "  {} {}",
+            style("✗").red(),
+            style(&failure.name).red().bold()
+        );
+        if let Some(panic) = &failure.panic {
+            for line in panic.lines() {
+                println!("    {}", line);
+            }
+        }
+        if let Some(left) = &failure.left {
+            println!("    {} {}", style(" left:").blue().bold(), left);
+        }
+        if let Some(right) = &failure.right {
+            println!("    {} {}", style("right:").blue().bold(), right);
+        }
+    }
+    println!();
+}
+
+fn save(progress: &Progress) {
+    if let Err(e) = progress.save() {
+        warn!("Failed to save your progress: {}", e);
+    }
+}
+
 // Compile the given Exercise and return an object with information
 // about the state of the compilation
-fn compile<'a, 'b>(
+fn compile<'a>(
     exercise: &'a Exercise,
-    progress_bar: &'b ProgressBar,
+    options: &'a CompileOptions,
+    progress_bar: &ProgressBar,
 ) -> Result<CompiledExercise<'a>, ()> {
-    let compilation_result = exercise.compile();
+    let compilation_result = exercise.compile(options);
 
     match compilation_result {
         Ok(compilation) => Ok(compilation),
+        Err(output) if output.cancelled => {
+            progress_bar.finish_and_clear();
+            Err(())
+        }
         Err(output) => {
             progress_bar.finish_and_clear();
             warn!(
@@ -127,18 +442,84 @@ fn compile<'a, 'b>(
                 exercise
             );
             println!("{}", output.stderr);
+            print_codes(&output);
             Err(())
         }
     }
 }
 
+// Name the lints clippy denied the exercise for and the error codes of the
+// compiler's errors, after its messages
+pub fn print_codes(output: &ExerciseOutput) {
+    let lints = output.lints();
+    if !lints.is_empty() {
+        println!("Lints that fired: {}", lints.join(", "));
+    }
+    match output.codes().as_slice() {
+        [] => {}
+        [code] => println!(
+            "Run `rustlings explain {}` to learn more about this error.",
+            code
+        ),
+        codes => println!(
+            "Run `rustlings explain <code>` with one of {} to learn more about these errors.",
+            codes.join(", ")
+        ),
+    }
+}
+
+// Tell the user why running the exercise's binary failed. If it printed
+// something other than expected, the difference is shown as well.
+pub fn warn_run_failure(exercise: &Exercise, output: &ExerciseOutput) {
+    let expected = match (&exercise.expected_output, output.unexpected_output) {
+        (Some(expected), true) => expected,
+        _ => {
+            warn_if_timed_out(exercise, output);
+            warn!("Ran {} with errors", exercise);
+            return;
+        }
+    };
+    warn!(
+        "Ran {} successfully, but it didn't print what it was expected to",
+        exercise
+    );
+    match expected {
+        ExpectedOutput::Regex(re) => {
+            println!("The output should match {}, but it was:", style(re).bold());
+            println!("{}", separator());
+            print!("{}", output.stdout);
+            println!("{}", separator());
+        }
+        ExpectedOutput::Exact(text) | ExpectedOutput::Trimmed(text) => {
+            println!(
+                "Differences between the {} and the {} output:",
+                style("- expected").red(),
+                style("+ actual").green()
+            );
+            println!("{}", separator());
+            diff::print_colored(text, &output.stdout);
+            println!("{}", separator());
+        }
+    }
+}
+
+// Tell the user that the exercise's binary was stopped for running too long
+fn warn_if_timed_out(exercise: &Exercise, output: &ExerciseOutput) {
+    if let (true, Some(timeout)) = (output.timed_out, exercise.timeout()) {
+        warn!(
+            "{} was stopped after running for longer than its timeout. Is there an infinite loop?",
+            format!("{} ({}s)", exercise, timeout.as_secs())
+        );
+    }
+}
+
 fn prompt_for_completion(exercise: &Exercise, prompt_output: Option<String>) -> bool {
     let context = match exercise.state() {
         State::Done => return true,
         State::Pending(context) => context,
     };
 
-    let no_emoji = env::var("NO_EMOJI").is_ok();
+    let no_emoji = !config::get().emoji;
 
     let clippy_success_msg = if no_emoji {
         "The code is compiling, and Clippy is happy!"
@@ -148,7 +529,7 @@ fn prompt_for_completion(exercise: &Exercise, prompt_output: Option<String>) ->
 
     let success_msg = match exercise.mode {
         Mode::Compile => "The code is compiling!",
-        Mode::Test => "The code is compiling, and the tests pass!",
+        Mode::Test | Mode::Cargo => "The code is compiling, and the tests pass!",
         Mode::Clippy => clippy_success_msg,
     };
 
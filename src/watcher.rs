@@ -0,0 +1,248 @@
+use notify::event::{Event, EventKind};
+use notify::{Config, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+// How often the polling watcher looks for changes
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// A change `watch` reacts to
+#[derive(Debug, PartialEq)]
+pub enum Change {
+    // A Rust file or the manifest of a cargo exercise was written, with its
+    // canonical path
+    Source(PathBuf),
+    // A manifest or rustlings.toml was written, so the exercises have to be loaded again
+    Manifest,
+}
+
+// Where the watcher looks for changes
+pub struct Watched {
+    // The directories the exercises are in, watched with everything in them
+    pub dirs: Vec<PathBuf>,
+    // The manifests and config files, watched through the directory they are
+    // in, as editors that save by renaming a new file over the old one would
+    // otherwise leave the watcher looking at a file that is gone
+    pub files: Vec<PathBuf>,
+}
+
+// Watches the exercises and manifests, with the native watcher of the
+// platform if that works, and by polling otherwise
+pub struct FileWatcher {
+    _watcher: Box<dyn Watcher + Send>,
+    // Why the watcher polls, `None` if it doesn't
+    pub polling: Option<String>,
+}
+
+impl FileWatcher {
+    // Start watching and send every change on `tx`. Polls when `poll` is set,
+    // when a watched directory is on a network filesystem the native watcher
+    // doesn't get events for, or when the native watcher fails, e.g. because
+    // the inotify limit has been reached.
+    pub fn new(watched: Watched, poll: bool, tx: Sender<Change>) -> notify::Result<FileWatcher> {
+        let watched = Resolved::new(watched);
+        let reason = if poll {
+            Some(String::from("--poll was given"))
+        } else {
+            watched.dirs.iter().find_map(|dir| {
+                remote_filesystem(dir).map(|fs| format!("{} is on {}", dir.display(), fs))
+            })
+        };
+        let reason = match reason {
+            Some(reason) => reason,
+            None => match RecommendedWatcher::new(watched.handler(tx.clone()), Config::default())
+                .and_then(|watcher| watched.start(watcher))
+            {
+                Ok(watcher) => {
+                    return Ok(FileWatcher {
+                        _watcher: watcher,
+                        polling: None,
+                    })
+                }
+                Err(e) => format!("the native file watcher failed: {}", e),
+            },
+        };
+        let config = Config::default().with_poll_interval(POLL_INTERVAL);
+        let watcher = PollWatcher::new(watched.handler(tx), config)?;
+        Ok(FileWatcher {
+            _watcher: watched.start(watcher)?,
+            polling: Some(reason),
+        })
+    }
+}
+
+// The watched paths, with the directories of the watched files, all canonical
+// so that they can be compared to the paths of events
+#[derive(Clone)]
+struct Resolved {
+    dirs: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+    parents: Vec<PathBuf>,
+}
+
+impl Resolved {
+    fn new(watched: Watched) -> Resolved {
+        let canonical = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let files: Vec<PathBuf> = watched
+            .files
+            .iter()
+            .map(|file| {
+                let parent = match file.parent() {
+                    Some(parent) if parent != Path::new("") => parent,
+                    _ => Path::new("."),
+                };
+                canonical(parent).join(file.file_name().unwrap_or_default())
+            })
+            .collect();
+        let mut parents: Vec<PathBuf> = files
+            .iter()
+            .filter_map(|file| file.parent().map(Path::to_path_buf))
+            .collect();
+        parents.sort();
+        parents.dedup();
+        Resolved {
+            dirs: watched.dirs.iter().map(|dir| canonical(dir)).collect(),
+            files,
+            parents,
+        }
+    }
+
+    fn start<W: Watcher + Send + 'static>(
+        &self,
+        mut watcher: W,
+    ) -> notify::Result<Box<dyn Watcher + Send>> {
+        for dir in &self.dirs {
+            watcher.watch(dir, RecursiveMode::Recursive)?;
+        }
+        for parent in &self.parents {
+            watcher.watch(parent, RecursiveMode::NonRecursive)?;
+        }
+        Ok(Box::new(watcher))
+    }
+
+    fn handler(&self, tx: Sender<Change>) -> impl FnMut(notify::Result<Event>) + Send + 'static {
+        let watched = self.clone();
+        move |event| {
+            // Errors of single events, e.g. about a file that was removed
+            // before it could be looked at, don't stop the watcher
+            if let Ok(event) = event {
+                for change in watched.changes(&event) {
+                    let _ = tx.send(change);
+                }
+            }
+        }
+    }
+
+    // What an event means to `watch`. Writing a file and renaming a file
+    // over another both count, removing one doesn't.
+    fn changes(&self, event: &Event) -> Vec<Change> {
+        if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+            return Vec::new();
+        }
+        let mut changes = Vec::new();
+        for path in &event.paths {
+            let change = if self.files.contains(path) {
+                Change::Manifest
+            } else if path.extension().is_some_and(|e| e == "rs")
+                || path.file_name().is_some_and(|name| name == "Cargo.toml")
+            {
+                match fs::canonicalize(path) {
+                    Ok(path) => Change::Source(path),
+                    Err(_) => continue,
+                }
+            } else {
+                continue;
+            };
+            if !changes.contains(&change) {
+                changes.push(change);
+            }
+        }
+        changes
+    }
+}
+
+// The name of the network filesystem the directory is on, if it is on one.
+// Changes to those made on another machine, or outside of a container or VM
+// the directory is mounted into, don't reach the native watcher.
+#[cfg(target_os = "linux")]
+fn remote_filesystem(dir: &Path) -> Option<&'static str> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(dir.as_os_str().as_bytes()).ok()?;
+    let mut stat = unsafe { std::mem::zeroed::<libc::statfs>() };
+    if unsafe { libc::statfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    match stat.f_type as u32 {
+        0x6969 => Some("NFS"),
+        0x517b => Some("SMB"),
+        0xff53_4d42 | 0xfe53_4d42 => Some("CIFS"),
+        0x0102_1997 => Some("9P"),
+        0x6573_5546 => Some("FUSE"),
+        _ => None,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn remote_filesystem(_dir: &Path) -> Option<&'static str> {
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use notify::event::{CreateKind, ModifyKind, RemoveKind, RenameMode};
+
+    fn event(kind: EventKind, paths: Vec<PathBuf>) -> Event {
+        Event {
+            kind,
+            paths,
+            attrs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_changes() {
+        let watched = Resolved::new(Watched {
+            dirs: vec![PathBuf::from("tests/fixture/state")],
+            files: vec![PathBuf::from("tests/fixture/state/info.toml")],
+        });
+        let source = fs::canonicalize("tests/fixture/state/pending_exercise.rs").unwrap();
+        let manifest = fs::canonicalize("tests/fixture/state/info.toml").unwrap();
+        let swap = source.with_extension("rs.swp");
+
+        let write = EventKind::Modify(ModifyKind::Any);
+        assert_eq!(
+            watched.changes(&event(write, vec![source.clone()])),
+            vec![Change::Source(source.clone())]
+        );
+        assert_eq!(
+            watched.changes(&event(write, vec![manifest])),
+            vec![Change::Manifest]
+        );
+        // The manifest of a cargo exercise is part of the exercise
+        let crate_manifest =
+            fs::canonicalize("tests/fixture/cargo/exercises/modules/Cargo.toml").unwrap();
+        assert_eq!(
+            watched.changes(&event(write, vec![crate_manifest.clone()])),
+            vec![Change::Source(crate_manifest)]
+        );
+        // An editor saving by renaming its swap file over the exercise
+        let rename = EventKind::Modify(ModifyKind::Name(RenameMode::Both));
+        assert_eq!(
+            watched.changes(&event(rename, vec![swap.clone(), source.clone()])),
+            vec![Change::Source(source.clone())]
+        );
+        assert_eq!(
+            watched.changes(&event(EventKind::Create(CreateKind::File), vec![swap])),
+            vec![]
+        );
+        assert_eq!(
+            watched.changes(&event(EventKind::Remove(RemoveKind::File), vec![source])),
+            vec![]
+        );
+    }
+}
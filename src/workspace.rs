@@ -0,0 +1,235 @@
+use regex::Regex;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// How old the workspace of a process has to be for `sweep` to remove it,
+// where there is no telling whether the process still runs
+#[cfg(not(target_os = "linux"))]
+const STALE_AFTER: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+// What older versions of rustlings left in the rustlings directory after
+// crashing: binaries, their debug info, and clippy and cargo directories
+const LEGACY_ARTIFACT_REGEX: &str = r"^temp_\d+_\d+(\.pdb|_clippy|_target)?$";
+
+static WORKSPACE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+// A directory of its own for everything one compilation produces, so that
+// several exercises can be compiled at the same time, in this or in other
+// processes. It is removed with everything in it once it goes away.
+pub struct Workspace {
+    dir: PathBuf,
+}
+
+impl Workspace {
+    pub fn new() -> io::Result<Workspace> {
+        let id = WORKSPACE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = root()?.join(format!("{}-{}", process::id(), id));
+        // Left over by an earlier process with the same id
+        let _ignored = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir)?;
+        Ok(Workspace { dir })
+    }
+
+    // The path of a file or directory in the workspace
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(name)
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ignored = fs::remove_dir_all(&self.dir);
+    }
+}
+
+// The directory the workspaces are created in, one per user so that users
+// sharing the temp directory don't get in each other's way
+fn root() -> io::Result<PathBuf> {
+    #[cfg(target_os = "linux")]
+    let name = format!("rustlings-{}", unsafe { libc::getuid() });
+    #[cfg(not(target_os = "linux"))]
+    let name = String::from("rustlings");
+    let root = env::temp_dir().join(name);
+    create_private_dir(&root)?;
+    Ok(root)
+}
+
+// Create the directory only the user may access. As anyone can tell its name
+// in advance, one that is already there is only used if it is the user's
+// own and no one else could write to it, rather than a directory or a symlink
+// another user put there. Older versions created it readable by everyone,
+// which is taken back.
+#[cfg(target_os = "linux")]
+fn create_private_dir(dir: &Path) -> io::Result<()> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+    if let Err(e) = fs::DirBuilder::new().mode(0o700).create(dir) {
+        if e.kind() != io::ErrorKind::AlreadyExists {
+            return Err(e);
+        }
+    }
+    let metadata = fs::symlink_metadata(dir)?;
+    if !metadata.is_dir()
+        || metadata.uid() != unsafe { libc::getuid() }
+        || metadata.mode() & 0o022 != 0
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "{} must be a directory that only you may access",
+                dir.display()
+            ),
+        ));
+    }
+    if metadata.mode() & 0o077 != 0 {
+        fs::set_permissions(dir, fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn create_private_dir(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)
+}
+
+// Remove the workspaces of processes that ended without cleaning up after
+// themselves, e.g. because they crashed, along with what older versions of
+// rustlings left in the rustlings directory
+pub fn sweep() {
+    if let Ok(root) = root() {
+        sweep_workspaces(&root);
+    }
+    sweep_legacy_artifacts(Path::new("."));
+}
+
+fn sweep_workspaces(root: &Path) {
+    let entries = match fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let pid = name
+            .to_str()
+            .and_then(|name| name.split('-').next())
+            .and_then(|pid| pid.parse::<u32>().ok());
+        if let Some(pid) = pid {
+            if pid != process::id() && !is_running(pid, &entry.path()) {
+                let _ignored = fs::remove_dir_all(entry.path());
+            }
+        }
+    }
+}
+
+// Whether the process that created the workspace still runs
+#[cfg(target_os = "linux")]
+fn is_running(pid: u32, _workspace: &Path) -> bool {
+    Path::new("/proc").join(pid.to_string()).exists()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_running(_pid: u32, workspace: &Path) -> bool {
+    fs::metadata(workspace)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| std::time::SystemTime::now().duration_since(modified).ok())
+        .map_or(true, |age| age < STALE_AFTER)
+}
+
+fn sweep_legacy_artifacts(dir: &Path) {
+    let re = Regex::new(LEGACY_ARTIFACT_REGEX).unwrap();
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        if entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| re.is_match(name))
+        {
+            let path = entry.path();
+            let _ignored = if path.is_dir() {
+                fs::remove_dir_all(path)
+            } else {
+                fs::remove_file(path)
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_workspace_is_removed() {
+        let workspace = Workspace::new().unwrap();
+        let binary = workspace.path("exercise");
+        fs::write(&binary, "").unwrap();
+        let dir = workspace.dir.clone();
+        drop(workspace);
+        assert!(!binary.exists());
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_workspaces_are_unique() {
+        assert_ne!(Workspace::new().unwrap().dir, Workspace::new().unwrap().dir);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_shared_root_is_refused() {
+        use std::os::unix::fs::PermissionsExt;
+        let root = PathBuf::from("target/test-workspace-root");
+        let _ = fs::remove_dir_all(&root);
+        create_private_dir(&root).unwrap();
+        assert_eq!(
+            fs::metadata(&root).unwrap().permissions().mode() & 0o777,
+            0o700
+        );
+        fs::set_permissions(&root, fs::Permissions::from_mode(0o755)).unwrap();
+        create_private_dir(&root).unwrap();
+        assert_eq!(
+            fs::metadata(&root).unwrap().permissions().mode() & 0o777,
+            0o700
+        );
+        fs::set_permissions(&root, fs::Permissions::from_mode(0o777)).unwrap();
+        assert_eq!(
+            create_private_dir(&root).unwrap_err().kind(),
+            io::ErrorKind::PermissionDenied
+        );
+        fs::remove_dir_all(&root).unwrap();
+
+        let link = PathBuf::from("target/test-workspace-link");
+        let _ = fs::remove_file(&link);
+        std::os::unix::fs::symlink(env::temp_dir(), &link).unwrap();
+        assert!(create_private_dir(&link).is_err());
+        fs::remove_file(&link).unwrap();
+    }
+
+    #[test]
+    fn test_sweep() {
+        let root = PathBuf::from("target/test-workspaces");
+        let _ = fs::remove_dir_all(&root);
+        let crashed = root.join(format!("{}-0", u32::MAX));
+        let running = root.join(format!("{}-0", process::id()));
+        fs::create_dir_all(&crashed).unwrap();
+        fs::create_dir_all(&running).unwrap();
+        sweep_workspaces(&root);
+        assert!(!crashed.exists() || !cfg!(target_os = "linux"));
+        assert!(running.exists());
+
+        fs::write(root.join("temp_123_4"), "").unwrap();
+        fs::write(root.join("temp_123_4.pdb"), "").unwrap();
+        fs::write(root.join("temperature.rs"), "").unwrap();
+        sweep_legacy_artifacts(&root);
+        assert!(!root.join("temp_123_4").exists());
+        assert!(!root.join("temp_123_4.pdb").exists());
+        assert!(root.join("temperature.rs").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
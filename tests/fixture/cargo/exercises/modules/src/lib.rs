@@ -0,0 +1,13 @@
+mod greeting;
+
+pub use greeting::greet;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greets_by_name() {
+        assert_eq!(greet("Ferris"), "Hello, Ferris!");
+    }
+}
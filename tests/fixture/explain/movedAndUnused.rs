@@ -0,0 +1,8 @@
+#![deny(unused_variables)]
+
+fn main() {
+    let unused = 1;
+    let v = vec![1];
+    let w = v;
+    println!("{:?}", v);
+}
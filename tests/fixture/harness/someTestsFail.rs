@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn passes() {
+        println!("THIS OUTPUT IS NOT SHOWN");
+    }
+
+    #[test]
+    fn passes_too() {}
+
+    #[test]
+    fn fails() {
+        assert_eq!(1 + 1, 3);
+    }
+}
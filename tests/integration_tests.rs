@@ -1,3 +1,6 @@
+// The older tests pass their arguments as `&[...]`
+#![allow(clippy::needless_borrows_for_generic_args)]
+
 use assert_cmd::prelude::*;
 use glob::glob;
 use predicates::boolean::PredicateBooleanExt;
@@ -199,6 +202,12 @@ fn run_rustlings_list() {
 
 #[test]
 fn run_rustlings_list_no_pending() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .arg("verify")
+        .current_dir("tests/fixture/success")
+        .assert()
+        .success();
     Command::cargo_bin("rustlings")
         .unwrap()
         .args(&["list"])
@@ -208,35 +217,746 @@ fn run_rustlings_list_no_pending() {
         .stdout(predicates::str::contains("Pending").not());
 }
 
+// A copy of the state fixture in which finished_exercise was verified, as the
+// progress of the fixture itself is not committed
+fn verified_state(name: &str) -> std::path::PathBuf {
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    copy_dir(std::path::Path::new("tests/fixture/state"), &dir);
+    let _ = std::fs::remove_file(dir.join(".rustlings-state"));
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["verify", "--all"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    dir
+}
+
 #[test]
 fn run_rustlings_list_both_done_and_pending() {
+    let dir = verified_state("state-list");
     Command::cargo_bin("rustlings")
         .unwrap()
         .args(&["list"])
-        .current_dir("tests/fixture/state")
+        .current_dir(&dir)
         .assert()
         .success()
         .stdout(predicates::str::contains("Done").and(predicates::str::contains("Pending")));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn run_rustlings_list_unverified_is_pending() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["list", "--names", "--unsolved"])
+        .current_dir("tests/fixture/failure")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("compFailure"));
 }
 
 #[test]
 fn run_rustlings_list_without_pending() {
+    let dir = verified_state("state-solved");
     Command::cargo_bin("rustlings")
         .unwrap()
         .args(&["list", "--solved"])
-        .current_dir("tests/fixture/state")
+        .current_dir(&dir)
         .assert()
         .success()
-        .stdout(predicates::str::contains("Pending").not());
+        .stdout(
+            predicates::str::contains("Pending")
+                .not()
+                .and(predicates::str::contains("finished_exercise")),
+        );
+    std::fs::remove_dir_all(&dir).unwrap();
 }
 
 #[test]
 fn run_rustlings_list_without_done() {
+    let dir = verified_state("state-unsolved");
     Command::cargo_bin("rustlings")
         .unwrap()
         .args(&["list", "--unsolved"])
-        .current_dir("tests/fixture/state")
+        .current_dir(&dir)
         .assert()
         .success()
         .stdout(predicates::str::contains("Done").not());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn run_rustlings_list_json() {
+    let dir = verified_state("state-json");
+    let output = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["list", "--format", "json"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["exercises"][0]["name"], "pending_exercise");
+    assert_eq!(
+        json["exercises"][0]["state"]["pending"][2]["important"],
+        true
+    );
+    assert_eq!(json["exercises"][2]["status"], "done");
+    assert_eq!(json["summary"]["total"], 3);
+}
+
+#[test]
+fn verify_fails_with_json_lines() {
+    let output = Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["verify", "--format", "jsonl"])
+        .current_dir("tests/fixture/failure")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let lines: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines[0]["type"], "exercise");
+    assert_eq!(lines[0]["outcome"], "compile_error");
+    assert_eq!(lines.last().unwrap()["type"], "summary");
+    assert_eq!(lines.last().unwrap()["failed"], 1);
+}
+
+#[test]
+fn verify_all_reports_every_failure() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["verify", "--all", "--jobs", "2"])
+        .current_dir("tests/fixture/failure")
+        .assert()
+        .code(1)
+        .stdout(
+            predicates::str::contains("compFailure")
+                .and(predicates::str::contains("testFailure"))
+                .and(predicates::str::contains("0 / 2 exercises pass")),
+        );
+}
+
+#[test]
+fn run_single_compile_timeout() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "infiniteLoop"])
+        .current_dir("tests/fixture/timeout")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("longer than its timeout"));
+}
+
+#[test]
+fn config_timeout_keeps_exercise_timeout() {
+    // The exercise stops after its own second, not after the minute of the config
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("config-timeout");
+    let _ = std::fs::remove_dir_all(&dir);
+    copy_dir(std::path::Path::new("tests/fixture/timeout"), &dir);
+    std::fs::write(dir.join("rustlings.toml"), "timeout = 60\n").unwrap();
+    let start = std::time::Instant::now();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "infiniteLoop"])
+        .current_dir(&dir)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("longer than its timeout"));
+    assert!(start.elapsed() < std::time::Duration::from_secs(30));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn run_single_compile_expected_output() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "rightOutput"])
+        .current_dir("tests/fixture/output")
+        .assert()
+        .success();
+}
+
+#[test]
+fn run_single_compile_unexpected_output() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "wrongOutput"])
+        .current_dir("tests/fixture/output")
+        .assert()
+        .code(1)
+        .stdout(
+            predicates::str::contains("- Hello, world!")
+                .and(predicates::str::contains("+ Hello, World!")),
+        );
+}
+
+#[test]
+fn run_single_test_failure_shows_failing_tests_only() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "someTestsFail"])
+        .current_dir("tests/fixture/harness")
+        .assert()
+        .code(1)
+        .stdout(
+            predicates::str::contains("2/3 tests passing")
+                .and(predicates::str::contains("tests::fails"))
+                .and(predicates::str::contains("right: 3"))
+                .and(predicates::str::contains("THIS OUTPUT IS NOT SHOWN").not()),
+        );
+}
+
+#[test]
+fn get_hints_one_level_at_a_time() {
+    let _ = std::fs::remove_file("tests/fixture/hints/.rustlings-state");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["hint", "multipleHints"])
+        .current_dir("tests/fixture/hints")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("First hint"))
+        .stdout(predicates::str::contains("Second hint").not())
+        .stdout(predicates::str::contains("1 more hint(s) left"));
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["hint", "multipleHints"])
+        .current_dir("tests/fixture/hints")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("First hint"))
+        .stdout(predicates::str::contains("Second hint"))
+        .stdout(predicates::str::contains("more hint(s) left").not());
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["list"])
+        .current_dir("tests/fixture/hints")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("2/2"));
+}
+
+#[test]
+fn solution_unlocks_after_all_hints() {
+    let _ = std::fs::remove_file("tests/fixture/solution/.rustlings-state");
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["solution", "withSolution"])
+        .current_dir("tests/fixture/solution")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("locked"));
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["hint", "withSolution"])
+        .current_dir("tests/fixture/solution")
+        .assert()
+        .success();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["solution", "withSolution"])
+        .current_dir("tests/fixture/solution")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Hello, world!"));
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["diff", "withSolution"])
+        .current_dir("tests/fixture/solution")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("@@ -1,3 +1,3 @@"))
+        .stdout(predicates::str::contains("println!(\"Hello, world!\");"));
+}
+
+#[test]
+fn reset_and_undo() {
+    let path = "tests/fixture/reset/resetMe.rs";
+    let _ = std::fs::remove_dir_all("tests/fixture/reset/.rustlings-backup");
+    std::fs::write(path, "fn main() {\n    println!(\"solved\");\n}\n").unwrap();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["reset", "resetMe"])
+        .current_dir("tests/fixture/reset")
+        .assert()
+        .success();
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        std::fs::read_to_string("tests/fixture/reset/pristine/resetMe.rs").unwrap()
+    );
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["reset", "--all", "--undo"])
+        .current_dir("tests/fixture/reset")
+        .assert()
+        .success();
+    assert!(std::fs::read_to_string(path).unwrap().contains("solved"));
+    std::fs::remove_file(path).unwrap();
+}
+
+// Copy the directory with everything in it
+fn copy_dir(from: &std::path::Path, to: &std::path::Path) {
+    std::fs::create_dir_all(to).unwrap();
+    for entry in std::fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            std::fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+#[test]
+fn verify_reuses_cached_compilations() {
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("cache-reuse");
+    let _ = std::fs::remove_dir_all(&dir);
+    copy_dir(std::path::Path::new("tests/fixture/success"), &dir);
+    let _ = std::fs::remove_dir_all(dir.join("target"));
+    let entries = || {
+        let mut entries: Vec<_> = std::fs::read_dir(dir.join("target/rustlings-cache"))
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap().path().join("entry.json");
+                let modified = std::fs::metadata(&entry).unwrap().modified().unwrap();
+                (entry, modified)
+            })
+            .collect();
+        entries.sort();
+        entries
+    };
+
+    let verify = || {
+        Command::cargo_bin("rustlings")
+            .unwrap()
+            .arg("verify")
+            .current_dir(&dir)
+            .assert()
+            .success();
+    };
+
+    verify();
+    let first = entries();
+    assert_eq!(first.len(), 2);
+    verify();
+    // Nothing was compiled and stored again
+    assert_eq!(entries(), first);
+}
+
+#[test]
+fn reset_stock_exercise() {
+    // A copy of the curriculum, as the exercises of the repository must stay untouched
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("reset-stock");
+    let _ = std::fs::remove_dir_all(&dir);
+    copy_dir(std::path::Path::new("exercises"), &dir.join("exercises"));
+    std::fs::copy("info.toml", dir.join("info.toml")).unwrap();
+    let path = dir.join("exercises/variables/variables1.rs");
+    std::fs::write(&path, "fn main() {}\n").unwrap();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["reset", "variables1"])
+        .current_dir(&dir)
+        .assert()
+        .success();
+    // Without a pristine directory next to it, the embedded original is restored
+    let reset = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        reset,
+        std::fs::read_to_string("pristine/exercises/variables/variables1.rs").unwrap()
+    );
+    assert!(reset.contains("\n// I AM NOT DONE\n"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn invalid_info_toml_reports_position() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["list"])
+        .current_dir("tests/fixture/invalid")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "info.toml:4:8: unknown mode `compil`",
+        ));
+}
+
+#[test]
+fn check_reports_missing_hints() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["check"])
+        .current_dir("tests/fixture/failure")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "info.toml:5:8: `compFailure` has no hint",
+        ));
+}
+
+#[test]
+fn stock_exercises_have_reference_solutions() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["check"])
+        .assert()
+        .success();
+    let mut info = String::new();
+    File::open("info.toml")
+        .unwrap()
+        .read_to_string(&mut info)
+        .unwrap();
+    for line in info.lines() {
+        if let Some(path) = line.strip_prefix("path = ") {
+            let path = path.trim_matches('"');
+            let solution = path.replacen("exercises/", "solutions/", 1);
+            assert!(
+                info.contains(&format!("solution = \"{}\"", solution)),
+                "{} has no solution",
+                path
+            );
+            assert_ne!(
+                std::fs::read(path).unwrap(),
+                std::fs::read(&solution).unwrap(),
+                "the solution of {} is a copy of it",
+                path
+            );
+        }
+    }
+}
+
+#[test]
+fn list_groups_exercise_packs() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["list"])
+        .current_dir("tests/fixture/packs")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("stockExercise"))
+        .stdout(predicates::str::contains("team/packExercise"))
+        .stdout(predicates::str::contains("extra/exercises/packExercise.rs"))
+        .stdout(predicates::str::contains("  team: 0 / 1"));
+}
+
+#[test]
+fn run_exercise_from_pack_flag() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--pack", "../packs/extra", "run", "team/packExercise"])
+        .current_dir("tests/fixture/success")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("from a pack"));
+}
+
+#[test]
+fn check_exercise_packs() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["check"])
+        .current_dir("tests/fixture/packs")
+        .assert()
+        .success();
+}
+
+#[test]
+fn cargo_exercise_without_tests_fails() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "untested"])
+        .current_dir("tests/fixture/cargo")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "exercises/untested has no tests, so there is nothing to check it with.",
+        ));
+}
+
+#[test]
+fn run_cargo_exercise_with_vendored_dependency() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "modules"])
+        .current_dir("tests/fixture/cargo")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Successfully tested exercises/modules",
+        ));
+}
+
+#[test]
+fn run_next_shows_readme_of_new_topic() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["run", "next"])
+        .current_dir("tests/fixture/topics")
+        .assert()
+        .code(1)
+        .stdout(
+            predicates::str::contains("Say hello with println!.")
+                .and(predicates::str::contains("  • Formatted print (https://")),
+        );
+}
+
+#[test]
+fn list_shows_topic_progress() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["list"])
+        .current_dir("tests/fixture/topics")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Topics:\n  greetings  [--------------------] 0 / 2",
+        ));
+}
+
+#[test]
+fn tui_requires_terminal() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["tui"])
+        .current_dir("tests/fixture/success")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("has to be run in a terminal"));
+}
+
+#[test]
+fn watch_shell_skips_and_quits() {
+    // A copy of its own, as skipping writes the progress next to the exercises
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("shell-watch");
+    let _ = std::fs::remove_dir_all(&dir);
+    copy_dir(std::path::Path::new("tests/fixture/shell"), &dir);
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["watch"])
+        .current_dir(&dir)
+        .with_stdin()
+        .buffer("progress\nskip\nlist\nquit\n")
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("You completed 0 / 2 exercises")
+                .and(predicates::str::contains("Skipped first"))
+                .and(predicates::str::contains("exercises/second.rs failed"))
+                .and(predicates::str::contains("first            \tSkipped")),
+        );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn verify_checks_skipped_exercises() {
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("shell-verify");
+    let _ = std::fs::remove_dir_all(&dir);
+    copy_dir(std::path::Path::new("tests/fixture/shell"), &dir);
+    std::fs::write(
+        dir.join(".rustlings-state"),
+        "[exercises.first]\nskipped = true\n\n[exercises.second]\nskipped = true\n",
+    )
+    .unwrap();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["verify"])
+        .current_dir(&dir)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("exercises/first.rs"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch_polls_when_asked_to() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["watch", "--poll", "--debounce", "50"])
+        .current_dir("tests/fixture/topics")
+        .with_stdin()
+        .buffer("quit\n")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Polling for changes, as --poll was given.",
+        ));
+}
+
+#[test]
+fn run_clippy_names_lints_that_fired() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "run", "denied"])
+        .current_dir("tests/fixture/lints")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "Lints that fired: clippy::needless_return",
+        ));
+}
+
+#[test]
+fn run_clippy_with_lint_configuration() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "run", "allowed"])
+        .current_dir("tests/fixture/lints")
+        .assert()
+        .success();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "run", "configured"])
+        .current_dir("tests/fixture/lints")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "Lints that fired: clippy::too_many_arguments",
+        ));
+}
+
+#[test]
+fn explain_error_code() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "explain", "e382"])
+        .current_dir("tests/fixture/success")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("E0382").and(predicates::str::contains("moved")));
+}
+
+#[test]
+fn explain_next_exercise_without_codes() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "explain"])
+        .current_dir("tests/fixture/failure")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "The errors of compFailure have no error codes to explain.",
+        ));
+}
+
+#[test]
+fn explain_next_exercise_leaves_out_rustc_lints() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "explain"])
+        .current_dir("tests/fixture/explain")
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("E0382")
+                .and(predicates::str::contains("unused_variables").not()),
+        );
+}
+
+#[test]
+fn fix_shows_suggestions_and_asks_first() {
+    let source = std::fs::read_to_string("tests/fixture/lints/needlessReturn.rs").unwrap();
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "fix", "denied"])
+        .current_dir("tests/fixture/lints")
+        .with_stdin()
+        .buffer("n\n")
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("-    return 42;\n+    42")
+                .and(predicates::str::contains("Nothing was changed.")),
+        );
+    assert_eq!(
+        std::fs::read_to_string("tests/fixture/lints/needlessReturn.rs").unwrap(),
+        source
+    );
+}
+
+#[test]
+fn config_set_then_get() {
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("config");
+    let _ = std::fs::remove_dir_all(&dir);
+    let rustlings = || {
+        let mut command = Command::cargo_bin("rustlings").unwrap();
+        command
+            .env("XDG_CONFIG_HOME", &dir)
+            .env_remove("RUSTLINGS_COLOR")
+            .current_dir("tests/fixture/success");
+        command
+    };
+    rustlings()
+        .args(["config", "set", "color", "never"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Set `color` in"));
+    rustlings()
+        .args(["config", "get", "color"])
+        .assert()
+        .success()
+        .stdout("never\n");
+    rustlings()
+        .args(["config", "get"])
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("color = \"never\"")
+                .and(predicates::str::contains("rustlings/config.toml"))
+                .and(predicates::str::contains("(default)")),
+        );
+    // The environment wins over the file
+    rustlings()
+        .args(["config", "get", "color"])
+        .env("RUSTLINGS_COLOR", "always")
+        .assert()
+        .success()
+        .stdout("always\n");
+    rustlings()
+        .args(["config", "set", "color", "blue"])
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("unknown variant `blue`"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn color_flag_wins_over_no_color() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "--color", "always", "run", "compFailure"])
+        .env("NO_COLOR", "1")
+        .current_dir("tests/fixture/failure")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("\u{1b}["));
+}
+
+#[test]
+fn no_color_without_a_terminal() {
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["--no-cache", "run", "compFailure"])
+        .current_dir("tests/fixture/failure")
+        .assert()
+        .code(1)
+        .stdout(
+            predicates::str::contains("expected pattern")
+                .and(predicates::str::contains("\u{1b}[").not()),
+        );
+    Command::cargo_bin("rustlings")
+        .unwrap()
+        .args(["config", "get", "color"])
+        .env("NO_COLOR", "1")
+        .env_remove("RUSTLINGS_COLOR")
+        .current_dir("tests/fixture/failure")
+        .assert()
+        .success()
+        .stdout("never\n");
 }